@@ -0,0 +1,253 @@
+//! Functionality pertaining to `Aabb`
+
+use core::fmt;
+use core::ops::{Add, Sub};
+
+use num::{Num, Integer, Float, One};
+
+use super::{Vector, VecItem};
+use super::math::{VecNum, VecFloat};
+use super::vecn::VecN;
+use super::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+/// An axis-aligned bounding box, spanning the inclusive region between two corner vectors
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Aabb<V> {
+    pub min: V,
+    pub max: V,
+}
+
+/// An axis-aligned bounding box, spanning the inclusive region between two corner vectors
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Aabb<V> {
+    pub min: V,
+    pub max: V,
+}
+
+/// A 2-dimensional axis-aligned bounding box
+pub type Aabb2<T> = Aabb<Vec2<T>>;
+
+/// A 3-dimensional axis-aligned bounding box
+pub type Aabb3<T> = Aabb<Vec3<T>>;
+
+impl<V> Aabb<V> {
+    /// Creates a new Aabb from its minimum and maximum corners
+    pub fn new(min: V, max: V) -> Self { Self { min, max } }
+}
+
+impl<V> Aabb<V> where V: Vector + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, V::Item: PartialOrd {
+    /// Creates the smallest Aabb that contains all of the given points, returning `None` if there are no points
+    pub fn from_points<I: IntoIterator<Item=V>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |mut aabb, p| {
+            let bounds = aabb.min.as_mut().iter_mut().zip(aabb.max.as_mut().iter_mut());
+            for ((min, max), e) in bounds.zip(p.as_ref().iter()) {
+                if *e < *min {
+                    *min = *e;
+                }
+                if *e > *max {
+                    *max = *e;
+                }
+            }
+            aabb
+        }))
+    }
+}
+
+impl<V: Copy + Add<Output=V> + Sub<Output=V>> Aabb<V> {
+    /// Creates a new Aabb from its center and its extent *(i.e: the distance from the center to each face)*
+    pub fn from_center_extent(center: V, extent: V) -> Self {
+        Self::new(center - extent, center + extent)
+    }
+}
+
+/// Implements the dimension-generic `Aabb` methods for boxes of a vector type
+macro_rules! impl_aabb {
+    ($V:ident $([$N:ident])?) => (
+        impl<T: VecItem + PartialOrd $(, const $N: usize)?> Aabb<$V<T $(, $N)?>> {
+            /// Returns `true` if the minimum corner of the box does not exceed the maximum corner on any axis
+            pub fn is_valid(&self) -> bool {
+                self.min.cmp_le(self.max).all()
+            }
+
+            /// Returns the smallest Aabb that contains both this box and `other`
+            pub fn union(&self, other: Self) -> Self {
+                Self::new(Self::min_of(self.min, other.min), Self::max_of(self.max, other.max))
+            }
+
+            /// Returns the region shared by this box and `other`, or `None` if they do not intersect
+            pub fn intersection(&self, other: Self) -> Option<Self> {
+                let aabb = Self::new(Self::max_of(self.min, other.min), Self::min_of(self.max, other.max));
+                if aabb.is_valid() { Some(aabb) } else { None }
+            }
+
+            /// Returns the smallest Aabb that contains both this box and the point `p`
+            pub fn include_point(&self, p: $V<T $(, $N)?>) -> Self {
+                Self::new(Self::min_of(self.min, p), Self::max_of(self.max, p))
+            }
+
+            /// Returns `true` if the point `p` lies within the box or upon its boundary
+            pub fn contains_point(&self, p: $V<T $(, $N)?>) -> bool {
+                p.cmp_ge(self.min).all() && p.cmp_le(self.max).all()
+            }
+
+            /// Returns `true` if `other` lies entirely within the box
+            pub fn contains_aabb(&self, other: Self) -> bool {
+                other.min.cmp_ge(self.min).all() && other.max.cmp_le(self.max).all()
+            }
+
+            /// Returns `true` if the box and `other` overlap or touch
+            pub fn intersects(&self, other: Self) -> bool {
+                self.min.cmp_le(other.max).all() && self.max.cmp_ge(other.min).all()
+            }
+
+            // Component-wise minimum and maximum of two vectors
+            fn min_of(a: $V<T $(, $N)?>, b: $V<T $(, $N)?>) -> $V<T $(, $N)?> {
+                $V::select(a.cmp_lt(b), a, b)
+            }
+
+            fn max_of(a: $V<T $(, $N)?>, b: $V<T $(, $N)?>) -> $V<T $(, $N)?> {
+                $V::select(a.cmp_gt(b), a, b)
+            }
+
+            /// Returns the point within the box that is closest to the point `p`
+            pub fn closest_point(&self, p: $V<T $(, $N)?>) -> $V<T $(, $N)?> {
+                Self::max_of(self.min, Self::min_of(self.max, p))
+            }
+        }
+
+        impl<T: VecItem + Num + PartialOrd $(, const $N: usize)?> Aabb<$V<T $(, $N)?>> {
+            /// Returns the box grown by `amount` on every side
+            pub fn expand(&self, amount: T) -> Self {
+                Self::new(self.min - amount, self.max + amount)
+            }
+
+            /// Returns the box shrunk by `amount` on every side
+            pub fn shrink(&self, amount: T) -> Self {
+                Self::new(self.min + amount, self.max - amount)
+            }
+
+            /// Calculates the size of the box along each axis
+            pub fn size(&self) -> $V<T $(, $N)?> {
+                self.max - self.min
+            }
+
+            /// Calculates the center of the box
+            pub fn center(&self) -> $V<T $(, $N)?> {
+                (self.min + self.max) / (T::one() + T::one())
+            }
+
+            /// Calculates the extent of the box *(i.e: the distance from the center to each face)*
+            pub fn extent(&self) -> $V<T $(, $N)?> {
+                self.size() / (T::one() + T::one())
+            }
+
+            /// Calculates the volume of the box *(for 2-dimensional boxes, this is the area)*
+            pub fn volume(&self) -> T {
+                self.size().product()
+            }
+        }
+
+        impl<T: VecItem + Float $(, const $N: usize)?> Aabb<$V<T $(, $N)?>> {
+            /// Calculates the signed distance from the surface of the box to the point `p`
+            ///
+            /// *Points outside the box have a positive distance and points inside the box have a negative distance*
+            pub fn signed_distance(&self, p: $V<T $(, $N)?>) -> T {
+                let q = (p - self.center()).map(|e| e.abs()) - self.extent();
+                let outside = q.map(|e| e.max(T::zero())).length();
+                let inside = q.elements().iter().fold(T::neg_infinity(), |a, e| a.max(*e)).min(T::zero());
+                outside + inside
+            }
+        }
+
+        impl<T: VecItem + Integer $(, const $N: usize)?> Aabb<$V<T $(, $N)?>> {
+            /// Returns an iterator over every lattice point within the box, including those upon its boundary
+            ///
+            /// *Points are produced with the `x` component varying fastest*
+            pub fn points(&self) -> AabbPoints<$V<T $(, $N)?>> {
+                AabbPoints {
+                    min: self.min,
+                    max: self.max,
+                    next: if self.is_valid() { Some(self.min) } else { None },
+                }
+            }
+        }
+
+        impl<T: VecItem + Integer $(, const $N: usize)?> IntoIterator for Aabb<$V<T $(, $N)?>> {
+            type Item = $V<T $(, $N)?>;
+            type IntoIter = AabbPoints<$V<T $(, $N)?>>;
+            fn into_iter(self) -> AabbPoints<$V<T $(, $N)?>> { self.points() }
+        }
+    );
+}
+
+impl_aabb!(VecN[N]);
+impl_aabb!(Vec1);
+impl_aabb!(Vec2);
+impl_aabb!(Vec3);
+impl_aabb!(Vec4);
+
+impl<T: VecItem + Num + PartialOrd> Aabb<Vec2<T>> {
+    /// Calculates the area of the box
+    pub fn area(&self) -> T {
+        self.volume()
+    }
+}
+
+impl<T: VecItem + Num + PartialOrd> Aabb<Vec3<T>> {
+    /// Calculates the total area of the faces of the box
+    pub fn surface_area(&self) -> T {
+        let s = self.size();
+        (s.x * s.y + s.y * s.z + s.z * s.x) * (T::one() + T::one())
+    }
+}
+
+/// An iterator over the lattice points within an integer `Aabb`
+#[derive(Clone)]
+pub struct AabbPoints<V> {
+    min: V,
+    max: V,
+    next: Option<V>,
+}
+
+impl<V> Iterator for AabbPoints<V>
+    where V: Vector + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, V::Item: Integer
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<V> {
+        let current = self.next?;
+
+        // Advance the first component that has not reached its maximum, resetting all components before it
+        let mut next = current;
+        let mut advanced = false;
+        for ((e, min), max) in next.as_mut().iter_mut().zip(self.min.as_ref().iter()).zip(self.max.as_ref().iter()) {
+            if *e < *max {
+                *e = *e + V::Item::one();
+                advanced = true;
+                break;
+            }
+            *e = *min;
+        }
+        self.next = if advanced { Some(next) } else { None };
+
+        Some(current)
+    }
+}
+
+// Debug and Display traits
+
+impl<V: fmt::Debug> fmt::Debug for Aabb<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(min: {:?}, max: {:?})", self.min, self.max)
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Aabb<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
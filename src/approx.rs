@@ -0,0 +1,223 @@
+//! Approximate equality comparisons for floating point types
+
+use core::fmt;
+
+use num::Float;
+
+use super::{Vector, VecItem};
+use super::vecn::VecN;
+use super::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+use super::mat2::Mat2;
+use super::mat3::Mat3;
+use super::mat4::Mat4;
+use super::quat::Quat;
+
+/// A trait for types that may be compared for approximate equality
+///
+/// Three kinds of comparison are supported:
+///
+/// - **Absolute** *(`.abs_diff_eq()`)*: the difference between the values is no greater than `epsilon`
+/// - **Relative** *(`.relative_eq()`)*: the difference is no greater than `epsilon`, or no greater than `max_relative`
+///   multiplied by the larger of the two magnitudes
+/// - **ULPs** *(`.ulps_eq()`)*: the difference is no greater than `epsilon`, or the values are no more than `max_ulps`
+///   representable floating point numbers apart
+///
+/// *Compound types such as vectors, matrices and quaternions are approximately equal when every one of their
+/// components is*
+pub trait ApproxEq {
+    /// The type used to express tolerances
+    type Epsilon: Copy;
+
+    /// The default absolute tolerance
+    fn default_epsilon() -> Self::Epsilon;
+
+    /// The default relative tolerance
+    fn default_max_relative() -> Self::Epsilon;
+
+    /// The default number of representable values by which two values may differ
+    fn default_max_ulps() -> u32;
+
+    /// Returns true if the values differ by no more than `epsilon`
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    /// Returns true if the values differ by no more than `epsilon`, or by no more than `max_relative` multiplied by the
+    /// larger of the two magnitudes
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+
+    /// Returns true if the values differ by no more than `epsilon`, or are no more than `max_ulps` representable values
+    /// apart
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// Returns true if the values are relatively equal, using the default tolerances
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($float:ty, $bits:ty) => (
+        impl ApproxEq for $float {
+            type Epsilon = $float;
+
+            fn default_epsilon() -> $float { <$float>::EPSILON }
+
+            fn default_max_relative() -> $float { <$float>::EPSILON }
+
+            fn default_max_ulps() -> u32 { 4 }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $float) -> bool {
+                // Handles infinities, which produce a NaN difference
+                self == other || (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $float, max_relative: $float) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+
+                (self - other).abs() <= self.abs().max(other.abs()) * max_relative
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $float, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_nan() || other.is_nan() || self.is_sign_positive() != other.is_sign_positive() {
+                    return false;
+                }
+
+                // Floats of the same sign are ordered in the same way as their bit patterns
+                let (a, b) = (self.to_bits() as $bits, other.to_bits() as $bits);
+                a.wrapping_sub(b).unsigned_abs() <= max_ulps as _
+            }
+        }
+    );
+}
+
+impl_approx_eq!(f32, i32);
+impl_approx_eq!(f64, i64);
+
+macro_rules! impl_approx_eq_vec {
+    ($V:ident $([$N:ident])?) => (
+        impl<T: VecItem + ApproxEq $(, const $N: usize)?> ApproxEq for $V<T $(, $N)?> {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> T::Epsilon { T::default_epsilon() }
+
+            fn default_max_relative() -> T::Epsilon { T::default_max_relative() }
+
+            fn default_max_ulps() -> u32 { T::default_max_ulps() }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                self.iter().zip(other.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                self.iter().zip(other.iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                self.iter().zip(other.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+    );
+}
+
+impl_approx_eq_vec!(VecN[N]);
+impl_approx_eq_vec!(Vec1);
+impl_approx_eq_vec!(Vec2);
+impl_approx_eq_vec!(Vec3);
+impl_approx_eq_vec!(Vec4);
+
+macro_rules! impl_approx_eq_mat {
+    ($mat:ident) => (
+        impl<T: VecItem + ApproxEq> ApproxEq for $mat<T> {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> T::Epsilon { T::default_epsilon() }
+
+            fn default_max_relative() -> T::Epsilon { T::default_max_relative() }
+
+            fn default_max_ulps() -> u32 { T::default_max_ulps() }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                self.cols.iter().zip(other.cols.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                self.cols.iter().zip(other.cols.iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                self.cols.iter().zip(other.cols.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+    );
+}
+
+impl_approx_eq_mat!(Mat2);
+impl_approx_eq_mat!(Mat3);
+impl_approx_eq_mat!(Mat4);
+
+/// *Quaternions are compared component-wise, so `q` and `-q` are not approximately equal despite representing the same
+/// rotation*
+impl<T: VecItem + Float + ApproxEq> ApproxEq for Quat<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon { T::default_epsilon() }
+
+    fn default_max_relative() -> T::Epsilon { T::default_max_relative() }
+
+    fn default_max_ulps() -> u32 { T::default_max_ulps() }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.to_vec4().abs_diff_eq(&other.to_vec4(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.to_vec4().relative_eq(&other.to_vec4(), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.to_vec4().ulps_eq(&other.to_vec4(), epsilon, max_ulps)
+    }
+}
+
+// Support for the assertion macros
+
+// The name of a component of a vector with the given number of components, for use in failure messages
+#[doc(hidden)]
+pub struct Component(pub usize, pub usize);
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match ["x", "y", "z", "w"].get(self.0) {
+            Some(name) if self.1 <= 4 => write!(f, "{}", name),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+// Panics with a description of the first pair of components that do not satisfy `eq`, if any
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_vec<V, F>(left: &V, right: &V, kind: &str, eq: F)
+    where V: Vector + AsRef<[V::Item]> + fmt::Debug, V::Item: fmt::Debug, F: Fn(&V::Item, &V::Item) -> bool
+{
+    let (l, r) = (left.as_ref(), right.as_ref());
+    if let Some(i) = (0..l.len()).find(|&i| !eq(&l[i], &r[i])) {
+        panic!(
+            "assertion failed: vectors are not {} equal at component {} ({:?} vs {:?})\n  left: {:?}\n right: {:?}",
+            kind, Component(i, l.len()), l[i], r[i], left, right,
+        );
+    }
+}
+
+// Allows the default tolerances to be found from a value, for use in the assertion macros
+#[doc(hidden)]
+pub fn defaults<T: ApproxEq>(_: &T) -> (T::Epsilon, T::Epsilon, u32) {
+    (T::default_epsilon(), T::default_max_relative(), T::default_max_ulps())
+}
@@ -0,0 +1,52 @@
+//! Functionality pertaining to numeric casts between vectors of different component types
+
+use core::fmt;
+
+use num::{NumCast, ToPrimitive, Bounded, Zero};
+
+/// The error produced when a component of a vector cannot be represented by the target component type
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct CastError {
+    index: usize,
+}
+
+impl CastError {
+    pub(crate) fn new(index: usize) -> Self { Self { index } }
+
+    /// Returns the index of the first component that could not be cast
+    pub fn index(&self) -> usize { self.index }
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.index {
+            0 => write!(f, "failed to cast component x of vector"),
+            1 => write!(f, "failed to cast component y of vector"),
+            2 => write!(f, "failed to cast component z of vector"),
+            3 => write!(f, "failed to cast component w of vector"),
+            i => write!(f, "failed to cast component {} of vector", i),
+        }
+    }
+}
+
+impl ::core::error::Error for CastError {}
+
+/// Casts a single component, returning an error referring to the component's index upon failure
+pub(crate) fn cast_item<T: ToPrimitive, U: NumCast>(item: T, index: usize) -> Result<U, CastError> {
+    U::from(item).ok_or(CastError::new(index))
+}
+
+/// Casts a single component, clamping it to the bounds of the target type upon overflow
+///
+/// *Values that are neither above nor below zero, such as NaN, become zero*
+pub(crate) fn saturating_cast_item<T, U>(item: T) -> U
+    where T: Copy + ToPrimitive + Zero + PartialOrd, U: NumCast + Bounded + Zero
+{
+    U::from(item).unwrap_or_else(|| if item > T::zero() {
+        U::max_value()
+    } else if item < T::zero() {
+        U::min_value()
+    } else {
+        U::zero()
+    })
+}
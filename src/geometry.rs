@@ -0,0 +1,533 @@
+//! Functionality pertaining to geometric primitives: `Plane3`, `Sphere`, `Circle`, `Triangle` and `Capsule`
+
+use core::fmt;
+use core::ops::Sub;
+
+use num::{Float, NumCast, Zero, One};
+
+use super::VecItem;
+use super::math::{VecDot, VecCross, VecFloat};
+use super::vecn::VecN;
+use super::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+use super::ray::{Ray2, Ray3, Segment2, Segment3, RayHit};
+
+pub mod closest;
+
+/// An infinite plane of points `p` that satisfy `normal.dot(p) == d`
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Plane3<T: VecItem> {
+    pub normal: Vec3<T>,
+    pub d: T,
+}
+
+/// An infinite plane of points `p` that satisfy `normal.dot(p) == d`
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Plane3<T: VecItem> {
+    pub normal: Vec3<T>,
+    pub d: T,
+}
+
+/// A sphere, defined by its center and radius
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Sphere<T: VecItem> {
+    pub center: Vec3<T>,
+    pub radius: T,
+}
+
+/// A sphere, defined by its center and radius
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Sphere<T: VecItem> {
+    pub center: Vec3<T>,
+    pub radius: T,
+}
+
+/// A circle, defined by its center and radius
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Circle<T: VecItem> {
+    pub center: Vec2<T>,
+    pub radius: T,
+}
+
+/// A circle, defined by its center and radius
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Circle<T: VecItem> {
+    pub center: Vec2<T>,
+    pub radius: T,
+}
+
+/// A triangle, defined by its three corners
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Triangle<V> {
+    pub a: V,
+    pub b: V,
+    pub c: V,
+}
+
+/// A triangle, defined by its three corners
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Triangle<V> {
+    pub a: V,
+    pub b: V,
+    pub c: V,
+}
+
+/// A 2-dimensional triangle
+pub type Triangle2<T> = Triangle<Vec2<T>>;
+
+/// A 3-dimensional triangle
+pub type Triangle3<T> = Triangle<Vec3<T>>;
+
+/// A capsule, consisting of every point within a given radius of a line segment
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Capsule<T: VecItem> {
+    pub start: Vec3<T>,
+    pub end: Vec3<T>,
+    pub radius: T,
+}
+
+/// A capsule, consisting of every point within a given radius of a line segment
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Capsule<T: VecItem> {
+    pub start: Vec3<T>,
+    pub end: Vec3<T>,
+    pub radius: T,
+}
+
+// Converts a constant into the floating point type `T`
+fn constant<T: Float>(x: f64) -> T {
+    <T as NumCast>::from(x).unwrap()
+}
+
+// The normalized direction from `center` to `p`, or the x axis if they coincide
+fn direction<V>(center: V, p: V) -> V
+    where V: VecFloat + VecDot + Copy + Default + Sub<Output=V> + AsMut<[V::Item]>, V::Item: Float
+{
+    let offset = p - center;
+    if offset.length_squared() > V::Item::zero() {
+        offset.norm()
+    } else {
+        let mut dir = V::default();
+        dir.as_mut()[0] = V::Item::one();
+        dir
+    }
+}
+
+impl<T: VecItem + Float> Plane3<T> {
+    /// Creates a new Plane3 from a normal and the value of `normal.dot(p)` for points `p` on the plane, normalizing
+    /// both
+    pub fn new(normal: Vec3<T>, d: T) -> Self {
+        let len = normal.length();
+        Self { normal: normal / len, d: d / len }
+    }
+
+    /// Creates a new Plane3 that passes through `point` with the given normal
+    pub fn from_point_normal(point: Vec3<T>, normal: Vec3<T>) -> Self {
+        let normal = normal.norm();
+        Self { normal, d: normal.dot(point) }
+    }
+
+    /// Creates a new Plane3 that passes through the points `a`, `b` and `c`, returning `None` if they are collinear
+    ///
+    /// *The normal faces towards the side from which the points appear counter-clockwise*
+    pub fn from_points(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Option<Self> {
+        let normal = (b - a).cross(c - a);
+        if normal.length_squared() <= T::zero() {
+            None
+        } else {
+            Some(Self::from_point_normal(a, normal))
+        }
+    }
+
+    /// Calculates the signed distance from the plane to the point `p`
+    ///
+    /// *Points on the side of the plane that the normal faces have a positive distance*
+    pub fn signed_distance(&self, p: Vec3<T>) -> T {
+        self.normal.dot(p) - self.d
+    }
+
+    /// Returns the point on the plane that is closest to the point `p` *(i.e: the projection of `p` onto the plane)*
+    pub fn closest_point(&self, p: Vec3<T>) -> Vec3<T> {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    /// Returns the plane with the same points, but with the opposite normal
+    pub fn flip(&self) -> Self {
+        Self { normal: -self.normal, d: -self.d }
+    }
+
+    /// Finds the intersection of a ray with the plane
+    pub fn intersect_ray(&self, ray: Ray3<T>) -> Option<RayHit<Vec3<T>>> {
+        ray.intersect_plane(self.normal, self.d)
+    }
+}
+
+macro_rules! impl_round {
+    ($ty:ident, $vec:ident, $ray:ident) => (
+        impl<T: VecItem + Float> $ty<T> {
+            /// Creates a new shape from its center and radius
+            pub fn new(center: $vec<T>, radius: T) -> Self { Self { center, radius } }
+
+            /// Calculates the signed distance from the surface of the shape to the point `p`
+            ///
+            /// *Points outside the shape have a positive distance and points inside the shape have a negative distance*
+            pub fn signed_distance(&self, p: $vec<T>) -> T {
+                self.center.distance(p) - self.radius
+            }
+
+            /// Returns the point on the surface of the shape that is closest to the point `p`
+            pub fn closest_point(&self, p: $vec<T>) -> $vec<T> {
+                self.center + self.normal_at(p) * self.radius
+            }
+
+            /// Returns the normalized surface normal of the shape at the point on its surface closest to `p`
+            pub fn normal_at(&self, p: $vec<T>) -> $vec<T> {
+                direction(self.center, p)
+            }
+
+            /// Returns `true` if the point `p` lies within the shape or upon its surface
+            pub fn contains_point(&self, p: $vec<T>) -> bool {
+                self.center.distance_squared(p) <= self.radius * self.radius
+            }
+
+            /// Finds the first intersection of a ray with the shape
+            pub fn intersect_ray(&self, ray: $ray<T>) -> Option<RayHit<$vec<T>>> {
+                ray.intersect_sphere(self.center, self.radius)
+            }
+        }
+    );
+}
+
+impl_round!(Sphere, Vec3, Ray3);
+impl_round!(Circle, Vec2, Ray2);
+
+impl<T: VecItem + Float> Sphere<T> {
+    /// Calculates the volume of the sphere
+    pub fn volume(&self) -> T {
+        constant::<T>(4.0 / 3.0 * ::core::f64::consts::PI) * self.radius.powi(3)
+    }
+
+    /// Calculates the area of the surface of the sphere
+    pub fn surface_area(&self) -> T {
+        constant::<T>(4.0 * ::core::f64::consts::PI) * self.radius.powi(2)
+    }
+}
+
+impl<T: VecItem + Float> Circle<T> {
+    /// Calculates the area of the circle
+    pub fn area(&self) -> T {
+        constant::<T>(::core::f64::consts::PI) * self.radius.powi(2)
+    }
+
+    /// Calculates the circumference of the circle
+    pub fn circumference(&self) -> T {
+        constant::<T>(2.0 * ::core::f64::consts::PI) * self.radius
+    }
+}
+
+impl<V> Triangle<V> {
+    /// Creates a new Triangle from its three corners
+    pub fn new(a: V, b: V, c: V) -> Self { Self { a, b, c } }
+}
+
+/// Implements the dimension-generic `Triangle` methods for triangles of a vector type
+macro_rules! impl_triangle {
+    ($V:ident $([$N:ident])?) => (
+        impl<T: VecItem + Float $(, const $N: usize)?> Triangle<$V<T $(, $N)?>> {
+            // The dot products of the edges `ab` and `ac`, along with the determinant of their Gram matrix
+            fn gram(&self) -> (T, T, T, T) {
+                let (ab, ac) = (self.b - self.a, self.c - self.a);
+                let (d00, d01, d11) = (ab.length_squared(), ab.dot(ac), ac.length_squared());
+                (d00, d01, d11, d00 * d11 - d01 * d01)
+            }
+
+            // Returns `true` if the triangle has no area
+            fn is_degenerate(&self, d00: T, d11: T, det: T) -> bool {
+                det <= T::epsilon() * d00 * d11
+            }
+
+            /// Calculates the area of the triangle
+            pub fn area(&self) -> T {
+                let (_, _, _, det) = self.gram();
+                det.max(T::zero()).sqrt() / constant::<T>(2.0)
+            }
+
+            /// Calculates the total length of the edges of the triangle
+            pub fn perimeter(&self) -> T {
+                self.a.distance(self.b) + self.b.distance(self.c) + self.c.distance(self.a)
+            }
+
+            /// Returns the centroid of the triangle *(i.e: the mean of its corners)*
+            pub fn centroid(&self) -> $V<T $(, $N)?> {
+                (self.a + self.b + self.c) / constant::<T>(3.0)
+            }
+
+            /// Calculates the barycentric coordinates of the point `p` with respect to the corners `a`, `b` and `c`,
+            /// returning `None` if the triangle is degenerate
+            ///
+            /// *For 3-dimensional triangles, `p` is first projected onto the plane of the triangle*
+            pub fn barycentric(&self, p: $V<T $(, $N)?>) -> Option<Vec3<T>> {
+                let (d00, d01, d11, det) = self.gram();
+                if self.is_degenerate(d00, d11, det) {
+                    return None;
+                }
+
+                let ap = p - self.a;
+                let (d20, d21) = (ap.dot(self.b - self.a), ap.dot(self.c - self.a));
+                let v = (d11 * d20 - d01 * d21) / det;
+                let w = (d00 * d21 - d01 * d20) / det;
+                Some(Vec3::new(T::one() - v - w, v, w))
+            }
+
+            /// Returns the point with the given barycentric coordinates with respect to the corners `a`, `b` and `c`
+            pub fn from_barycentric(&self, bary: Vec3<T>) -> $V<T $(, $N)?> {
+                self.a * bary.x + self.b * bary.y + self.c * bary.z
+            }
+
+            /// Returns the circumcenter of the triangle *(i.e: the point equidistant from all three corners)*, returning
+            /// `None` if the triangle is degenerate
+            pub fn circumcenter(&self) -> Option<$V<T $(, $N)?>> {
+                let (d00, d01, d11, det) = self.gram();
+                if self.is_degenerate(d00, d11, det) {
+                    return None;
+                }
+
+                let s = d11 * (d00 - d01) / (det + det);
+                let t = d00 * (d11 - d01) / (det + det);
+                Some(self.a + (self.b - self.a) * s + (self.c - self.a) * t)
+            }
+
+            /// Returns the incenter of the triangle *(i.e: the center of the largest circle that fits within it)*
+            pub fn incenter(&self) -> $V<T $(, $N)?> {
+                let (la, lb, lc) = (self.b.distance(self.c), self.c.distance(self.a), self.a.distance(self.b));
+                (self.a * la + self.b * lb + self.c * lc) / (la + lb + lc)
+            }
+
+            /// Returns the point on the triangle that is closest to the point `p`
+            pub fn closest_point(&self, p: $V<T $(, $N)?>) -> $V<T $(, $N)?> {
+                let (a, b, c) = (self.a, self.b, self.c);
+                let (ab, ac) = (b - a, c - a);
+
+                // Vertex region of `a`
+                let ap = p - a;
+                let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+                if d1 <= T::zero() && d2 <= T::zero() {
+                    return a;
+                }
+
+                // Vertex region of `b`
+                let bp = p - b;
+                let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+                if d3 >= T::zero() && d4 <= d3 {
+                    return b;
+                }
+
+                // Edge region of `ab`
+                let vc = d1 * d4 - d3 * d2;
+                if vc <= T::zero() && d1 >= T::zero() && d3 <= T::zero() {
+                    return a + ab * (d1 / (d1 - d3));
+                }
+
+                // Vertex region of `c`
+                let cp = p - c;
+                let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+                if d6 >= T::zero() && d5 <= d6 {
+                    return c;
+                }
+
+                // Edge region of `ac`
+                let vb = d5 * d2 - d1 * d6;
+                if vb <= T::zero() && d2 >= T::zero() && d6 <= T::zero() {
+                    return a + ac * (d2 / (d2 - d6));
+                }
+
+                // Edge region of `bc`
+                let va = d3 * d6 - d5 * d4;
+                if va <= T::zero() && d4 - d3 >= T::zero() && d5 - d6 >= T::zero() {
+                    return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+                }
+
+                // Face region
+                let denom = va + vb + vc;
+                a + ab * (vb / denom) + ac * (vc / denom)
+            }
+
+            /// Calculates the distance from the triangle to the point `p`
+            pub fn distance(&self, p: $V<T $(, $N)?>) -> T {
+                self.closest_point(p).distance(p)
+            }
+        }
+    );
+}
+
+impl_triangle!(VecN[N]);
+impl_triangle!(Vec1);
+impl_triangle!(Vec2);
+impl_triangle!(Vec3);
+impl_triangle!(Vec4);
+
+impl<T: VecItem + Float> Triangle<Vec2<T>> {
+    /// Returns `true` if the point `p` lies within the triangle or upon its boundary
+    pub fn contains_point(&self, p: Vec2<T>) -> bool {
+        self.barycentric(p).is_some_and(|bary| bary.cmp_ge(Vec3::splat(T::zero())).all())
+    }
+
+    /// Calculates the signed distance from the boundary of the triangle to the point `p`
+    ///
+    /// *Points outside the triangle have a positive distance and points inside the triangle have a negative distance*
+    pub fn signed_distance(&self, p: Vec2<T>) -> T {
+        let edges = [(self.a, self.b), (self.b, self.c), (self.c, self.a)];
+        let boundary = edges.iter()
+            .map(|&(start, end)| Segment2::new(start, end).distance(p))
+            .fold(T::infinity(), T::min);
+        if self.contains_point(p) { -boundary } else { boundary }
+    }
+}
+
+impl<T: VecItem + Float> Triangle<Vec3<T>> {
+    /// Returns the normalized normal of the triangle
+    ///
+    /// *The normal faces towards the side from which the corners appear counter-clockwise*
+    pub fn normal(&self) -> Vec3<T> {
+        (self.b - self.a).cross(self.c - self.a).norm()
+    }
+
+    /// Returns the plane in which the triangle lies, returning `None` if the triangle is degenerate
+    pub fn plane(&self) -> Option<Plane3<T>> {
+        Plane3::from_points(self.a, self.b, self.c)
+    }
+
+    /// Calculates the signed distance from the triangle to the point `p`
+    ///
+    /// *Points on the side of the triangle that the normal faces have a positive distance. Degenerate triangles have no
+    /// normal, so the unsigned distance is returned for them*
+    pub fn signed_distance(&self, p: Vec3<T>) -> T {
+        let distance = self.distance(p);
+        let (d00, _, d11, det) = self.gram();
+        if self.is_degenerate(d00, d11, det) {
+            return distance;
+        }
+        if self.normal().dot(p - self.a) < T::zero() { -distance } else { distance }
+    }
+
+    /// Finds the intersection of a ray with the triangle
+    pub fn intersect_ray(&self, ray: Ray3<T>) -> Option<RayHit<Vec3<T>>> {
+        ray.intersect_triangle(self.a, self.b, self.c)
+    }
+}
+
+impl<T: VecItem + Float> Capsule<T> {
+    /// Creates a new Capsule from the ends of its central segment and its radius
+    pub fn new(start: Vec3<T>, end: Vec3<T>, radius: T) -> Self { Self { start, end, radius } }
+
+    /// Returns the central segment of the capsule
+    pub fn segment(&self) -> Segment3<T> {
+        Segment3::new(self.start, self.end)
+    }
+
+    /// Calculates the signed distance from the surface of the capsule to the point `p`
+    ///
+    /// *Points outside the capsule have a positive distance and points inside the capsule have a negative distance*
+    pub fn signed_distance(&self, p: Vec3<T>) -> T {
+        self.segment().distance(p) - self.radius
+    }
+
+    /// Returns the point on the surface of the capsule that is closest to the point `p`
+    pub fn closest_point(&self, p: Vec3<T>) -> Vec3<T> {
+        let center = self.segment().closest_point(p);
+        center + direction(center, p) * self.radius
+    }
+
+    /// Returns the normalized surface normal of the capsule at the point on its surface closest to `p`
+    pub fn normal_at(&self, p: Vec3<T>) -> Vec3<T> {
+        direction(self.segment().closest_point(p), p)
+    }
+
+    /// Returns `true` if the point `p` lies within the capsule or upon its surface
+    pub fn contains_point(&self, p: Vec3<T>) -> bool {
+        self.signed_distance(p) <= T::zero()
+    }
+
+    /// Calculates the volume of the capsule
+    pub fn volume(&self) -> T {
+        let cylinder = self.start.distance(self.end) * self.radius.powi(2);
+        constant::<T>(::core::f64::consts::PI) * (cylinder + constant::<T>(4.0 / 3.0) * self.radius.powi(3))
+    }
+
+    /// Calculates the area of the surface of the capsule
+    pub fn surface_area(&self) -> T {
+        let cylinder = self.start.distance(self.end) * self.radius;
+        constant::<T>(2.0 * ::core::f64::consts::PI) * (cylinder + self.radius.powi(2) * constant(2.0))
+    }
+}
+
+// Debug and Display traits
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Plane3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(normal: {:?}, d: {:?})", self.normal, self.d)
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Plane3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.normal, self.d)
+    }
+}
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Sphere<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(center: {:?}, radius: {:?})", self.center, self.radius)
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Sphere<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.center, self.radius)
+    }
+}
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Circle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(center: {:?}, radius: {:?})", self.center, self.radius)
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Circle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.center, self.radius)
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Triangle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(a: {:?}, b: {:?}, c: {:?})", self.a, self.b, self.c)
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Triangle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.a, self.b, self.c)
+    }
+}
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Capsule<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(start: {:?}, end: {:?}, radius: {:?})", self.start, self.end, self.radius)
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Capsule<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.start, self.end, self.radius)
+    }
+}
@@ -0,0 +1,173 @@
+//! Closest-point queries between pairs of 3-dimensional primitives
+//!
+//! *Each function returns the closest point upon each of its two arguments, in order, along with the squared distance
+//! between them. Where the primitives intersect, both points lie at a point of intersection and the distance is zero*
+
+use num::Float;
+
+use super::super::VecItem;
+use super::super::math::{VecDot, VecCross};
+use super::super::vec3::Vec3;
+use super::super::ray::Segment3;
+use super::Triangle3;
+
+/// The result of a closest-point query between two primitives
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClosestPoints<T: VecItem> {
+    /// The point upon the first primitive that is closest to the second
+    pub a: Vec3<T>,
+    /// The point upon the second primitive that is closest to the first
+    pub b: Vec3<T>,
+    /// The squared distance between the two points
+    pub distance_squared: T,
+}
+
+impl<T: VecItem + Float> ClosestPoints<T> {
+    /// Creates a new ClosestPoints from a pair of points, calculating the squared distance between them
+    pub fn new(a: Vec3<T>, b: Vec3<T>) -> Self {
+        Self { a, b, distance_squared: a.distance_squared(b) }
+    }
+
+    /// Calculates the distance between the two points
+    pub fn distance(&self) -> T {
+        self.distance_squared.sqrt()
+    }
+
+    /// Returns the result with the two points swapped
+    pub fn swap(&self) -> Self {
+        Self { a: self.b, b: self.a, distance_squared: self.distance_squared }
+    }
+
+    // Returns whichever result has the smaller distance
+    fn min(self, other: Self) -> Self {
+        if other.distance_squared < self.distance_squared { other } else { self }
+    }
+}
+
+fn clamp01<T: Float>(x: T) -> T {
+    x.max(T::zero()).min(T::one())
+}
+
+// Finds the point at which the segment passes through the triangle, if any, using the Möller–Trumbore algorithm
+fn segment_triangle_intersection<T: VecItem + Float>(seg: Segment3<T>, tri: Triangle3<T>) -> Option<Vec3<T>> {
+    let dir = seg.end - seg.start;
+    let (e1, e2) = (tri.b - tri.a, tri.c - tri.a);
+    let p = dir.cross(e2);
+    let det = e1.dot(p);
+    // The segment is parallel to the triangle when `det` is negligible relative to the lengths it is the product of
+    if det * det <= T::epsilon() * T::epsilon() * e1.length_squared() * p.length_squared() {
+        return None;
+    }
+
+    let inv_det = T::one() / det;
+    let s = seg.start - tri.a;
+    let u = s.dot(p) * inv_det;
+    let q = s.cross(e1);
+    let v = dir.dot(q) * inv_det;
+    let t = e2.dot(q) * inv_det;
+    if u < T::zero() || v < T::zero() || u + v > T::one() || t < T::zero() || t > T::one() {
+        return None;
+    }
+
+    Some(seg.start + dir * t)
+}
+
+// The edges of a triangle, as segments
+fn edges<T: VecItem + Float>(tri: Triangle3<T>) -> [Segment3<T>; 3] {
+    [Segment3::new(tri.a, tri.b), Segment3::new(tri.b, tri.c), Segment3::new(tri.c, tri.a)]
+}
+
+/// Finds the closest points between two segments
+///
+/// *Segments whose length is negligible relative to the distances involved are treated as points*
+pub fn segment_segment<T: VecItem + Float>(a: Segment3<T>, b: Segment3<T>) -> ClosestPoints<T> {
+    let (d1, d2, r) = (a.end - a.start, b.end - b.start, a.start - b.start);
+    let (len1, len2, f) = (d1.length_squared(), d2.length_squared(), d2.dot(r));
+    let point = T::epsilon() * (len1 + len2 + r.length_squared());
+
+    let (s, t) = if len1 <= point && len2 <= point {
+        (T::zero(), T::zero())
+    } else if len1 <= point {
+        (T::zero(), clamp01(f / len2))
+    } else {
+        let c = d1.dot(r);
+        if len2 <= point {
+            (clamp01(-c / len1), T::zero())
+        } else {
+            // Find the closest points of the infinite lines, then clamp each to its segment in turn
+            let bd = d1.dot(d2);
+            let denom = len1 * len2 - bd * bd;
+            let s = if denom > T::zero() { clamp01((bd * f - c * len2) / denom) } else { T::zero() };
+            let t = (bd * s + f) / len2;
+            if t < T::zero() {
+                (clamp01(-c / len1), T::zero())
+            } else if t > T::one() {
+                (clamp01((bd - c) / len1), T::one())
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    ClosestPoints::new(a.start + d1 * s, b.start + d2 * t)
+}
+
+/// Finds the closest point upon a triangle to the point `p`
+pub fn point_triangle<T: VecItem + Float>(p: Vec3<T>, tri: Triangle3<T>) -> ClosestPoints<T> {
+    ClosestPoints::new(p, tri.closest_point(p))
+}
+
+/// Finds the closest points between a segment and a triangle
+pub fn segment_triangle<T: VecItem + Float>(seg: Segment3<T>, tri: Triangle3<T>) -> ClosestPoints<T> {
+    if let Some(p) = segment_triangle_intersection(seg, tri) {
+        return ClosestPoints::new(p, p);
+    }
+
+    // Otherwise, the closest points involve either an end of the segment or an edge of the triangle
+    let ends = point_triangle(seg.start, tri).min(point_triangle(seg.end, tri));
+    edges(tri).iter().fold(ends, |closest, &edge| closest.min(segment_segment(seg, edge)))
+}
+
+/// Finds the closest point upon an oriented box to the point `p`
+///
+/// *The box is described by its center, its three mutually perpendicular normalized axes and its half-extent along
+/// each axis*
+pub fn point_obb<T>(p: Vec3<T>, center: Vec3<T>, axes: [Vec3<T>; 3], half_extents: Vec3<T>) -> ClosestPoints<T>
+    where T: VecItem + Float
+{
+    let d = p - center;
+    let closest = axes.iter().zip(half_extents.iter()).fold(center, |closest, (axis, &extent)| {
+        closest + *axis * d.dot(*axis).max(-extent).min(extent)
+    });
+    ClosestPoints::new(p, closest)
+}
+
+/// Finds the closest points between two triangles
+pub fn triangle_triangle<T: VecItem + Float>(a: Triangle3<T>, b: Triangle3<T>) -> ClosestPoints<T> {
+    // Triangles that intersect must have an edge of one passing through the other
+    for &edge in edges(a).iter() {
+        if let Some(p) = segment_triangle_intersection(edge, b) {
+            return ClosestPoints::new(p, p);
+        }
+    }
+    for &edge in edges(b).iter() {
+        if let Some(p) = segment_triangle_intersection(edge, a) {
+            return ClosestPoints::new(p, p);
+        }
+    }
+
+    // Otherwise, the closest points lie upon a pair of edges, or a corner of one triangle and the face of the other
+    let mut closest = ClosestPoints::new(a.a, b.a);
+    for &ea in edges(a).iter() {
+        for &eb in edges(b).iter() {
+            closest = closest.min(segment_segment(ea, eb));
+        }
+    }
+    for &p in [a.a, a.b, a.c].iter() {
+        closest = closest.min(point_triangle(p, b));
+    }
+    for &p in [b.a, b.b, b.c].iter() {
+        closest = closest.min(point_triangle(p, a).swap());
+    }
+    closest
+}
@@ -3,17 +3,29 @@
 //! # Features
 //!
 //! - Basic vector operations
-//! - Basic primitive operations
+//! - Square matrix types with common matrix operations
+//! - Quaternion rotations
+//! - Axis-aligned bounding boxes
+//! - Oriented bounding boxes with separating axis overlap tests and point set fitting
+//! - Rays and line segments with intersection queries
+//! - Planes, spheres, circles, triangles and capsules with distance and closest point queries
+//! - Closest point queries between segments, triangles and oriented boxes
+//! - Basic primitive operations, with primitives on either side of the operator
+//! - Bitwise and logical vector operations
+//! - Checked, wrapping, saturating and overflowing vector arithmetic
 //! - Basic mathematic operations upon vectors
+//! - Statistical functions over collections of vectors
+//! - GLSL-style interpolation functions upon vectors
+//! - Cross products, reflection, refraction, projection and angles between vectors
+//! - Checked, saturating and lossy conversion between primitive vectors of different types
+//! - Approximate equality comparisons and assertions for floating point vectors, matrices and quaternions
 //! - Macros that make manipulating vectors simpler
 //! - Vector serialization
-//! - Hash support
+//! - Hash support, including total ordering and hashing of floating point vectors
 //!
 //! # Coming Soon
 //!
-//! - Bitwise vector operations
 //! - More mathematic functions
-//! - Conversion between primitive vectors of different types
 //!
 //! # Examples
 //!
@@ -24,6 +36,7 @@
 //!
 //! fn main() {
 //! 	// Coord supports 4 multi-variable vector types: Vec1, Vec2, Vec3 and Vec4
+//! 	// VecN provides the same functionality for any number of components
 //! 	let mut v = vec3!(1.0, 2.5, 3.0);
 //!
 //! 	// Coord supports common mathematical operations for both primitive and vector types
@@ -45,9 +58,8 @@
 //! ```
 
 #![no_std]
-#![feature(alloc)]
-
-#![feature(euclidean_division)]
+// The crate example is tab-indented
+#![allow(clippy::tabs_in_doc_comments)]
 
 extern crate num;
 #[cfg(feature = "serialize")]
@@ -56,18 +68,25 @@ extern crate serde_derive;
 #[cfg(feature = "serialize")]
 extern crate serde;
 
+#[macro_use]
+pub mod macros;
+pub mod vecn;
 pub mod vec1;
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
+pub mod mat2;
+pub mod mat3;
+pub mod mat4;
+pub mod quat;
+pub mod aabb;
+pub mod obb;
+pub mod ray;
+pub mod geometry;
+pub mod cast;
+pub mod ord;
+pub mod approx;
 pub mod math;
-#[macro_use]
-pub mod macros;
-
-use math::{VecNum, VecInt, VecUnsigned, VecSigned, VecFloat};
-
-#[cfg(feature = "serialize")]
-use serde::{Serialize, Deserialize};
 
 /// A trait implemented by all types that can exist within a vector
 pub trait VecItem: Copy + Clone + Default + PartialEq {}
@@ -94,6 +113,9 @@ impl VecItem for i128 {}
 impl VecItem for f32 {}
 impl VecItem for f64 {}
 
+impl<T: VecItem> VecItem for core::num::Wrapping<T> {}
+impl<T: VecItem> VecItem for core::num::Saturating<T> {}
+
 #[cfg(not(feature = "large_defaults"))]
 pub mod defaults {
     //! This module contains several type definitions that make working with `coord` simpler and faster
@@ -137,6 +159,66 @@ pub mod defaults {
     pub type Vec3f = vec3::Vec3<f32>;
     /// A 4-dimensional floating point vector type
     pub type Vec4f = vec4::Vec4<f32>;
+
+    /// A 2x2 unsigned integer matrix type
+    pub type Mat2u = mat2::Mat2<u32>;
+    /// A 3x3 unsigned integer matrix type
+    pub type Mat3u = mat3::Mat3<u32>;
+    /// A 4x4 unsigned integer matrix type
+    pub type Mat4u = mat4::Mat4<u32>;
+
+    /// A 2x2 signed integer matrix type
+    pub type Mat2i = mat2::Mat2<i32>;
+    /// A 3x3 signed integer matrix type
+    pub type Mat3i = mat3::Mat3<i32>;
+    /// A 4x4 signed integer matrix type
+    pub type Mat4i = mat4::Mat4<i32>;
+
+    /// A 2x2 floating point matrix type
+    pub type Mat2f = mat2::Mat2<f32>;
+    /// A 3x3 floating point matrix type
+    pub type Mat3f = mat3::Mat3<f32>;
+    /// A 4x4 floating point matrix type
+    pub type Mat4f = mat4::Mat4<f32>;
+
+    /// A floating point quaternion type
+    pub type Quatf = quat::Quat<f32>;
+
+    /// A 2-dimensional signed integer axis-aligned bounding box type
+    pub type Aabb2i = aabb::Aabb2<i32>;
+    /// A 3-dimensional signed integer axis-aligned bounding box type
+    pub type Aabb3i = aabb::Aabb3<i32>;
+
+    /// A 2-dimensional floating point axis-aligned bounding box type
+    pub type Aabb2f = aabb::Aabb2<f32>;
+    /// A 3-dimensional floating point axis-aligned bounding box type
+    pub type Aabb3f = aabb::Aabb3<f32>;
+
+    /// A 3-dimensional floating point oriented bounding box type
+    pub type Obb3f = obb::Obb3<f32>;
+
+    /// A 2-dimensional floating point ray type
+    pub type Ray2f = ray::Ray2<f32>;
+    /// A 3-dimensional floating point ray type
+    pub type Ray3f = ray::Ray3<f32>;
+
+    /// A 2-dimensional floating point line segment type
+    pub type Segment2f = ray::Segment2<f32>;
+    /// A 3-dimensional floating point line segment type
+    pub type Segment3f = ray::Segment3<f32>;
+
+    /// A floating point plane type
+    pub type Plane3f = geometry::Plane3<f32>;
+    /// A floating point sphere type
+    pub type Spheref = geometry::Sphere<f32>;
+    /// A floating point circle type
+    pub type Circlef = geometry::Circle<f32>;
+    /// A 2-dimensional floating point triangle type
+    pub type Triangle2f = geometry::Triangle2<f32>;
+    /// A 3-dimensional floating point triangle type
+    pub type Triangle3f = geometry::Triangle3<f32>;
+    /// A floating point capsule type
+    pub type Capsulef = geometry::Capsule<f32>;
 }
 
 #[cfg(feature = "large_defaults")]
@@ -182,6 +264,66 @@ pub mod defaults {
     pub type Vec3f = vec3::Vec3<f64>;
     /// A 4-dimensional floating point vector type
     pub type Vec4f = vec4::Vec4<f64>;
+
+    /// A 2x2 unsigned integer matrix type
+    pub type Mat2u = mat2::Mat2<u64>;
+    /// A 3x3 unsigned integer matrix type
+    pub type Mat3u = mat3::Mat3<u64>;
+    /// A 4x4 unsigned integer matrix type
+    pub type Mat4u = mat4::Mat4<u64>;
+
+    /// A 2x2 signed integer matrix type
+    pub type Mat2i = mat2::Mat2<i64>;
+    /// A 3x3 signed integer matrix type
+    pub type Mat3i = mat3::Mat3<i64>;
+    /// A 4x4 signed integer matrix type
+    pub type Mat4i = mat4::Mat4<i64>;
+
+    /// A 2x2 floating point matrix type
+    pub type Mat2f = mat2::Mat2<f64>;
+    /// A 3x3 floating point matrix type
+    pub type Mat3f = mat3::Mat3<f64>;
+    /// A 4x4 floating point matrix type
+    pub type Mat4f = mat4::Mat4<f64>;
+
+    /// A floating point quaternion type
+    pub type Quatf = quat::Quat<f64>;
+
+    /// A 2-dimensional signed integer axis-aligned bounding box type
+    pub type Aabb2i = aabb::Aabb2<i64>;
+    /// A 3-dimensional signed integer axis-aligned bounding box type
+    pub type Aabb3i = aabb::Aabb3<i64>;
+
+    /// A 2-dimensional floating point axis-aligned bounding box type
+    pub type Aabb2f = aabb::Aabb2<f64>;
+    /// A 3-dimensional floating point axis-aligned bounding box type
+    pub type Aabb3f = aabb::Aabb3<f64>;
+
+    /// A 3-dimensional floating point oriented bounding box type
+    pub type Obb3f = obb::Obb3<f64>;
+
+    /// A 2-dimensional floating point ray type
+    pub type Ray2f = ray::Ray2<f64>;
+    /// A 3-dimensional floating point ray type
+    pub type Ray3f = ray::Ray3<f64>;
+
+    /// A 2-dimensional floating point line segment type
+    pub type Segment2f = ray::Segment2<f64>;
+    /// A 3-dimensional floating point line segment type
+    pub type Segment3f = ray::Segment3<f64>;
+
+    /// A floating point plane type
+    pub type Plane3f = geometry::Plane3<f64>;
+    /// A floating point sphere type
+    pub type Spheref = geometry::Sphere<f64>;
+    /// A floating point circle type
+    pub type Circlef = geometry::Circle<f64>;
+    /// A 2-dimensional floating point triangle type
+    pub type Triangle2f = geometry::Triangle2<f64>;
+    /// A 3-dimensional floating point triangle type
+    pub type Triangle3f = geometry::Triangle3<f64>;
+    /// A floating point capsule type
+    pub type Capsulef = geometry::Capsule<f64>;
 }
 
 pub mod prelude {
@@ -198,23 +340,50 @@ pub mod prelude {
 
     pub use super::Vector;
 
+    pub use super::vecn::VecN;
     pub use super::vec1::Vec1;
     pub use super::vec2::Vec2;
     pub use super::vec3::Vec3;
     pub use super::vec4::Vec4;
 
+    pub use super::mat2::Mat2;
+    pub use super::mat3::Mat3;
+    pub use super::mat4::Mat4;
+
+    pub use super::quat::Quat;
+
+    pub use super::aabb::{Aabb, Aabb2, Aabb3};
+
+    pub use super::obb::{Obb3, Penetration};
+
+    pub use super::ray::{Ray, Ray2, Ray3, Segment, Segment2, Segment3, RayHit};
+
+    pub use super::geometry::{Plane3, Sphere, Circle, Triangle, Triangle2, Triangle3, Capsule};
+
+    pub use super::approx::ApproxEq;
+
+    pub use super::cast::CastError;
+
+    pub use super::ord::{TotalOrd, OrdF32, OrdF64};
+
     pub use math::VecNum;
+    pub use math::VecMap;
     pub use math::VecDot;
+    pub use math::VecCross;
     pub use math::VecInt;
     pub use math::VecUnsigned;
     pub use math::VecSigned;
     pub use math::VecFloat;
+    pub use math::VecInterp;
 
     pub use super::defaults::*;
 }
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
+    use self::std::format;
     use super::prelude::*;
 
     #[test]
@@ -361,22 +530,496 @@ mod tests {
 
     #[test]
     fn dot_product_vec3f(){
-        let zero = vec3!(0.0, 0.0, 0.0);
-        let one = vec3!(1.0, 1.0, 1.0);
-        let right = vec3!(1.0, 0.0, 0.0);
-        let left = vec3!(-1.0, 0.0, 0.0);
-        let up = vec3!(0.0, 1.0, 0.0);
-
-        /// floating point equality
-        fn f_eq(a: f64, b: f64) -> bool {
-            (b - a).abs() < 0.01
+        let zero = vec3!(0.0f64, 0.0, 0.0);
+        let one = vec3!(1.0f64, 1.0, 1.0);
+        let right = vec3!(1.0f64, 0.0, 0.0);
+        let left = vec3!(-1.0f64, 0.0, 0.0);
+        let up = vec3!(0.0f64, 1.0, 0.0);
+
+        assert!(zero.dot(zero).approx_eq(&0.0));
+        assert!(one.dot(one).approx_eq(&3.0));
+        assert!(right.dot(up).approx_eq(&0.0));
+        assert!(right.dot(right).approx_eq(&1.0));
+        assert!(right.dot(left).approx_eq(&-1.0));
+    }
+
+    #[test]
+    fn indexing_and_iteration() {
+        let mut v = vec3!(1, 2, 3);
+        assert_eq!((v[0], v[1], v[2]), (1, 2, 3));
+        v[1] = 5;
+        assert_eq!(v, vec3!(1, 5, 3));
+
+        for e in &mut v {
+            *e *= 2;
         }
+        v.iter_mut().for_each(|e| *e += 1);
+        assert_eq!(v.as_slice(), &[3, 11, 7]);
+        assert_eq!(v.iter().sum::<i32>(), 21);
+        assert_eq!((&v).into_iter().count(), 3);
+        assert_eq!(v.into_iter().rev().collect::<Vec3<i32>>(), vec3!(7, 11, 3));
+        assert_eq!((1..5).collect::<Vec4<u8>>(), vec4!(1, 2, 3, 4));
+        v.as_mut_slice()[2] = 0;
+        assert_eq!(v.z, 0);
+    }
 
-        assert!(f_eq(zero.dot(zero), 0.0));
-        assert!(f_eq(one.dot(one), 3.0));
-        assert!(f_eq(right.dot(up), 0.0));
-        assert!(f_eq(right.dot(right), 1.0));
-        assert!(f_eq(right.dot(left), -1.0));
+    #[test]
+    #[should_panic]
+    fn collect_too_few() {
+        let _ = (0..2).collect::<Vec3<u8>>();
+    }
+
+    #[test]
+    fn slice_reinterpretation() {
+        use core::mem::{size_of, align_of};
+        assert_eq!(size_of::<Vec3<f32>>(), 3 * size_of::<f32>());
+        assert_eq!(align_of::<Vec4<u8>>(), align_of::<u8>());
+
+        let mut vs = [vec3!(1.0, 2.0, 3.0), vec3!(4.0, 5.0, 6.0)];
+        assert_eq!(Vec3::flatten_slice(&vs), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        Vec3::flatten_slice_mut(&mut vs)[4] = 0.0;
+        assert_eq!(vs[1], vec3!(4.0, 0.0, 6.0));
+
+        let mut flat = [1u16, 2, 3, 4, 5, 6];
+        assert_eq!(Vec2::from_flat_slice(&flat), Some(&[vec2!(1, 2), vec2!(3, 4), vec2!(5, 6)][..]));
+        assert_eq!(Vec3::from_flat_slice(&flat).map(|vs| vs.len()), Some(2));
+        assert_eq!(Vec4::from_flat_slice(&flat), None);
+        Vec3::from_flat_slice_mut(&mut flat).unwrap()[1].y = 9;
+        assert_eq!(flat, [1, 2, 3, 4, 9, 6]);
+    }
+
+    #[test]
+    fn lexicographic_ordering() {
+        assert!(vec3!(1, 2, 3) < vec3!(1, 3, 0));
+        assert!(vec2!(2, 0) > vec2!(1, 9));
+        assert_eq!(vec2!(1.0, f32::NAN).partial_cmp(&vec2!(1.0, 2.0)), None);
+        assert_eq!(vec2!(0.0, f32::NAN).partial_cmp(&vec2!(1.0, 2.0)), Some(core::cmp::Ordering::Less));
+
+        let mut vs = [vec3!(2u8, 0, 0), vec3!(1, 5, 5), vec3!(1, 5, 2), vec3!(0, 9, 9)];
+        vs.sort();
+        assert_eq!(vs, [vec3!(0, 9, 9), vec3!(1, 5, 2), vec3!(1, 5, 5), vec3!(2, 0, 0)]);
+        assert_eq!(vs.iter().max(), Some(&vec3!(2, 0, 0)));
+        assert_eq!(vec2!(1, 9).min(vec2!(2, 0)), vec2!(1, 9));
+        assert_eq!(vec2!(5, 5).clamp(vec2!(1, 9), vec2!(4, 0)), vec2!(4, 0));
+    }
+
+    #[test]
+    fn total_ordering() {
+        use self::std::collections::HashSet;
+
+        let nan = OrdF32::from(f32::NAN);
+        assert_eq!(nan, TotalOrd(-f32::NAN));
+        assert!(TotalOrd(f32::INFINITY) < nan);
+        assert!(TotalOrd(-0.0f32) < TotalOrd(0.0));
+        assert!(TotalOrd(f64::NEG_INFINITY) < TotalOrd(-1.0e300));
+
+        let mut vs = [vec2!(1.5, f32::NAN), vec2!(-0.0, 3.0), vec2!(1.5, -2.0), vec2!(0.0, 1.0)]
+            .map(|v| v.convert_to::<OrdF32>());
+        vs.sort();
+        let sorted = vs.map(|v| v.convert_to::<f32>());
+        assert_eq!(sorted[..3], [vec2!(-0.0, 3.0), vec2!(0.0, 1.0), vec2!(1.5, -2.0)]);
+        assert!(sorted[3].y.is_nan());
+
+        let nan_pos = vec3!(f32::NAN, 0.0, 0.0);
+        let welded = [vec3!(1.0, 2.0, 3.0), vec3!(0.5, 0.5, 0.5), vec3!(1.0, 2.0, 3.0), nan_pos, nan_pos]
+            .iter()
+            .map(|v| v.convert_to::<OrdF32>())
+            .collect::<HashSet<_>>();
+        assert_eq!(welded.len(), 3);
+    }
+
+    #[test]
+    fn approximate_equality() {
+        use approx::ApproxEq;
+
+        let a = vec3!(0.1f32, 0.2, 0.3) * 3.0;
+        let b = vec3!(0.3f32, 0.6, 0.9);
+        assert!(a.approx_eq(&b));
+        assert!(a.abs_diff_eq(&(b + 0.001), 0.01));
+        assert!(!a.abs_diff_eq(&(b + 0.1), 0.01));
+        assert!(vec2!(1000.0f64, 1.0).relative_eq(&vec2!(1001.0, 1.0), 0.0, 0.001));
+        assert!(!vec2!(1000.0f64, 1.0).relative_eq(&vec2!(1001.0, 1.1), 0.0, 0.001));
+
+        let next = f32::from_bits(1.0f32.to_bits() + 3);
+        assert!(vec2!(1.0f32, -0.0).ulps_eq(&vec2!(next, 0.0), 0.0, 4));
+        assert!(!vec2!(1.0f32, 0.0).ulps_eq(&vec2!(next, 0.0), 0.0, 2));
+        assert!(!1.0f32.ulps_eq(&-1.0, 0.0, u32::MAX));
+        assert!(!f32::NAN.approx_eq(&f32::NAN));
+        assert!(f64::INFINITY.approx_eq(&f64::INFINITY));
+        assert!(!f64::INFINITY.approx_eq(&f64::MAX));
+
+        let m = Mat2::from_rows(vec2!(4.0f32, 7.0), vec2!(2.0, 6.0));
+        assert!((m * m.inverse().unwrap()).abs_diff_eq(&Mat2::identity(), 1e-6));
+        let q = Quat::from_axis_angle(vec3!(0.0f32, 0.0, 1.0), 1.0);
+        assert!((q * q.inverse()).abs_diff_eq(&Quat::identity(), 1e-6));
+
+        assert_vec_approx_eq!(a, b);
+        assert_vec_approx_eq!(a, b + 0.001, epsilon = 0.01);
+        assert_vec_approx_eq!(vec2!(1000.0f64, 1.0), vec2!(1001.0, 1.0), max_relative = 0.001);
+        assert_vec_ulps_eq!(vec2!(1.0f32, 2.0), vec2!(next, 2.0));
+        assert_vec_ulps_eq!(vec2!(1.0f32, 2.0), vec2!(next, 2.0), max_ulps = 3,);
+    }
+
+    #[test]
+    #[should_panic(expected = "at component z (3.0 vs 3.5)")]
+    fn approximate_equality_failure() {
+        assert_vec_approx_eq!(vec4!(1.0f32, 2.0, 3.0, 4.0), vec4!(1.0, 2.0, 3.5, 4.5), epsilon = 0.1);
+    }
+
+    #[test]
+    fn extension_and_truncation() {
+        let v2 = vec2!(1, 2);
+        let v3 = v2.extend(3);
+        assert_eq!(v3, vec3!(1, 2, 3));
+        assert_eq!(v3.extend(4), vec4!(1, 2, 3, 4));
+        assert_eq!(v3.extend(4).truncate(), v3);
+        assert_eq!(v3.truncate(), v2);
+
+        assert_eq!(vec3!(v2, 9), vec3!(1, 2, 9));
+        assert_eq!(vec3!(9, v2), vec3!(9, 1, 2));
+        assert_eq!(vec4!(v3, 1), vec4!(1, 2, 3, 1));
+        assert_eq!(vec4!(0, v3), vec4!(0, 1, 2, 3));
+        assert_eq!(vec4!(v2, v2), vec4!(1, 2, 1, 2));
+        assert_eq!(vec4!(v2, 7, 8), vec4!(1, 2, 7, 8));
+        assert_eq!(vec4!(7, v2, 8), vec4!(7, 1, 2, 8));
+        assert_eq!(vec4!(7, 8, v2), vec4!(7, 8, 1, 2));
+        assert_eq!(vec4!(vec3!(0.5, 1.5, 2.5), 1.0), vec4!(0.5, 1.5, 2.5, 1.0));
+    }
+
+    #[test]
+    fn homogeneous_coordinates() {
+        let p = vec3!(2.0, -4.0, 6.0);
+        assert_eq!(p.to_homogeneous_point(), vec4!(2.0, -4.0, 6.0, 1.0));
+        assert_eq!(p.to_homogeneous_dir(), vec4!(2.0, -4.0, 6.0, 0.0));
+        assert_eq!(p.to_homogeneous_point().from_homogeneous(), Some(p));
+        assert_eq!(vec4!(2.0, -4.0, 6.0, 2.0).from_homogeneous(), Some(vec3!(1.0, -2.0, 3.0)));
+        assert_eq!(p.to_homogeneous_dir().from_homogeneous(), None);
+        assert_eq!(vec4!(1.0, 1.0, 1.0, 1e-17f64).from_homogeneous(), None);
+        assert_eq!(vec4!(1e-20f64, 0.0, 0.0, 1e-20).from_homogeneous(), Some(vec3!(1.0, 0.0, 0.0)));
+
+        let m = Mat4::from_rows(
+            vec4!(1.0, 0.0, 0.0, 5.0),
+            vec4!(0.0, 1.0, 0.0, 0.0),
+            vec4!(0.0, 0.0, 1.0, 0.0),
+            vec4!(0.0, 0.0, 0.0, 1.0),
+        );
+        assert_eq!((m * p.to_homogeneous_point()).from_homogeneous(), Some(vec3!(7.0, -4.0, 6.0)));
+        assert_eq!((m * p.to_homogeneous_dir()).truncate(), p);
+    }
+
+    #[test]
+    fn constants_and_constructors() {
+        const ORIGIN: Vec3f = Vec3::new(0.0, 0.0, 0.0);
+        const GREY: Vec4<u8> = Vec4::splat(128);
+        const CORNER: VecN<i32, 5> = VecN::from_array([1; 5]);
+
+        assert_eq!(ORIGIN, Vec3f::ZERO);
+        assert_eq!(GREY, vec4!(128, 128, 128, 128));
+        assert_eq!(CORNER, VecN::<i32, 5>::ONE);
+
+        assert_eq!(Vec2::<u8>::UNIT_Y, vec2!(0, 1));
+        assert_eq!(Vec3::<f64>::UNIT_Z, vec3!(0.0, 0.0, 1.0));
+        assert_eq!(Vec4::<i128>::UNIT_W, vec4!(0, 0, 0, 1));
+        assert_eq!(Vec1::<f32>::UNIT_X, vec1!(1.0));
+        assert_eq!(Vec2::<bool>::TRUE, !Vec2b::FALSE);
+
+        assert_eq!(Vec3::<i16>::zero(), Vec3::<i16>::ZERO);
+        assert_eq!(Vec4::<f32>::one(), Vec4::<f32>::ONE);
+        assert_eq!(Vec2::<u32>::splat(7), vec2!(7, 7));
+        assert_eq!(Vec3::<i64>::unit_y(), Vec3::<i64>::UNIT_Y);
+        assert_eq!(Vec4::<f64>::unit_w(), Vec4::<f64>::UNIT_W);
+        assert_eq!(VecN::<u8, 6>::unit(4).elements(), [0, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn struct_literals_and_patterns() {
+        let mut v = Vec3 { x: 1, y: 2, z: 3 };
+        v.y += 5;
+        let Vec3 { x, y, z } = v;
+        assert_eq!((x, y, z), (1, 7, 3));
+
+        let Vec4 { w, .. } = vec4!(0.0, 0.0, 0.0, 2.5);
+        assert_eq!(w, 2.5);
+
+        let n: VecN<i32, 3> = v.into();
+        assert_eq!(n.elements(), [1, 7, 3]);
+        assert_eq!(Vec3::from(n), v);
+        assert_eq!(v.as_slice(), &[1, 7, 3]);
+    }
+
+    #[test]
+    fn horizontal_reductions() {
+        let v = vec4!(3.0, -1.5, 7.0, 7.0);
+        assert_eq!(v.min_element(), -1.5);
+        assert_eq!(v.max_element(), 7.0);
+        assert_eq!(v.argmin(), 1);
+        assert_eq!(v.argmax(), 2);
+        assert_eq!(vec3!(0.2f32, -0.9, 0.4).abs().argmax(), 1);
+        assert_eq!(vec1!(5u8).argmin(), 0);
+
+        assert_eq!(vec3!(1, 2, 3).fold(10, |a, e| a * 10 + e), 10123);
+        assert_eq!(vec3!(1, 2, 3).fold(0u64, |a, e| a + e as u64), 6);
+        assert_eq!(vec3!(4, 9, 2).reduce(|a, b| a.max(b)), 9);
+
+        let scaled = vec3!(1.5f32, 2.0, 3.0).zip_map(vec3!(2u8, 3, 4), |a, b| a * b as f32);
+        assert_eq!(scaled, vec3!(3.0, 6.0, 12.0));
+        assert_eq!(vec2!(1, 5).zip_map(vec2!(3, 3), |a, b| a < b), vec2!(true, false));
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let vs = [vec3!(1, 2, 3), vec3!(4, 5, 6), vec3!(-1, 0, 1)];
+        assert_eq!(vs.iter().sum::<Vec3<i32>>(), vec3!(4, 7, 10));
+        assert_eq!(vs.iter().copied().product::<Vec3<i32>>(), vec3!(-4, 0, 18));
+        assert_eq!(core::iter::empty::<Vec2f>().sum::<Vec2f>(), vec2!(0.0, 0.0));
+    }
+
+    #[test]
+    fn statistics() {
+        use math::stats;
+
+        let ps = [vec2!(0.0, 0.0), vec2!(4.0, 0.0), vec2!(4.0, 2.0), vec2!(0.0, 2.0)];
+        assert_eq!(stats::centroid(&ps[..]), Some(vec2!(2.0, 1.0)));
+        assert_eq!(stats::centroid(ps.iter().copied().take(2)), Some(vec2!(2.0, 0.0)));
+        assert_eq!(stats::centroid(core::iter::empty::<Vec2<f32>>()), None);
+        assert_eq!(stats::weighted_centroid([(ps[0], 3.0), (ps[1], 1.0)]), Some(vec2!(1.0, 0.0)));
+        assert_eq!(stats::weighted_centroid([(ps[0], 0.0)]), None);
+
+        let vs = [vec3!(1, 7, -2), vec3!(3, -1, 5), vec3!(2, 4, 0)];
+        assert_eq!(stats::component_min(&vs[..]), Some(vec3!(1, -1, -2)));
+        assert_eq!(stats::component_max(vs), Some(vec3!(3, 7, 5)));
+
+        assert_eq!(stats::variance(ps), Some(vec2!(4.0, 1.0)));
+
+        let line = [vec3!(0.0, 0.0, 1.0), vec3!(2.0, 4.0, 1.0), vec3!(4.0, 8.0, 1.0)];
+        let cov = stats::covariance(&line[..]).unwrap();
+        assert_eq!(cov.elements(), [[8.0 / 3.0, 16.0 / 3.0, 0.0], [16.0 / 3.0, 32.0 / 3.0, 0.0], [0.0, 0.0, 0.0]]);
+        assert_eq!(stats::covariance(core::iter::empty::<Vec3<f64>>()), None);
+    }
+
+    #[test]
+    fn negation_and_remainder() {
+        let v = vec3!(1.5, -2.0, 0.0);
+        assert_eq!(-v, vec3!(-1.5, 2.0, -0.0));
+        assert_eq!(-&vec2!(3, -4), vec2!(-3, 4));
+
+        let mut p = vec3!(17, -3, 32);
+        assert_eq!(p % 16, vec3!(1, -3, 0));
+        assert_eq!(p % vec3!(5, 2, 7), vec3!(2, -1, 4));
+        p %= 10;
+        assert_eq!(p, vec3!(7, -3, 2));
+        p %= vec3!(4, 4, 4);
+        assert_eq!(p, vec3!(3, -3, 2));
+    }
+
+    #[test]
+    fn scalar_lhs_operations() {
+        let v = vec3!(1.0f32, 2.0, 4.0);
+        assert_eq!(2.0 * v, vec3!(2.0, 4.0, 8.0));
+        assert_eq!(1.0 + v, vec3!(2.0, 3.0, 5.0));
+        assert_eq!(8.0 / v, vec3!(8.0, 4.0, 2.0));
+        assert_eq!(10i32 - vec2!(3, 12), vec2!(7, -2));
+        assert_eq!(7u8 % vec2!(2, 4), vec2!(1, 3));
+        assert_eq!(3u64 * &vec4!(1, 2, 3, 4), vec4!(3, 6, 9, 12));
+    }
+
+    #[test]
+    fn reference_operations() {
+        let (a, b) = (vec3!(1, 2, 3), vec3!(4, 5, 6));
+        let (ra, rb) = (&a, &b);
+        assert_eq!(ra + rb, vec3!(5, 7, 9));
+        assert_eq!(ra - b, vec3!(-3, -3, -3));
+        assert_eq!(a * rb, vec3!(4, 10, 18));
+        assert_eq!(rb / 2, vec3!(2, 2, 3));
+        assert_eq!(!&vec2!(true, false), vec2!(false, true));
+
+        let mut c = a;
+        c += &b;
+        c <<= &vec3!(1, 1, 1);
+        assert_eq!(c, vec3!(10, 14, 18));
+
+        fn sum_all<'a, V: 'a + Copy + core::ops::Add<&'a V, Output=V>>(init: V, vs: &'a [V]) -> V {
+            vs.iter().fold(init, |a, b| a + b)
+        }
+        assert_eq!(sum_all(vec2!(0, 0), &[vec2!(1, 2), vec2!(3, 4)]), vec2!(4, 6));
+    }
+
+    #[test]
+    fn overflow_arithmetic() {
+        let (a, b) = (vec3!(250u8, 10, 0), vec3!(10u8, 10, 1));
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(b.checked_add(b), Some(vec3!(20, 20, 2)));
+        assert_eq!(a.checked_sub_scalar(1), None);
+        assert_eq!(b.checked_mul_scalar(20), Some(vec3!(200, 200, 20)));
+        assert_eq!(b.checked_div(vec3!(2, 5, 0)), None);
+        assert_eq!(a.checked_div_scalar(5), Some(vec3!(50, 2, 0)));
+
+        assert_eq!(a.wrapping_add(b), vec3!(4, 20, 1));
+        assert_eq!(a.wrapping_sub_scalar(1), vec3!(249, 9, 255));
+        assert_eq!(a.wrapping_mul(b), vec3!(196, 100, 0));
+
+        assert_eq!(a.saturating_add(b), vec3!(255, 20, 1));
+        assert_eq!(a.saturating_sub_scalar(20), vec3!(230, 0, 0));
+        assert_eq!(vec2!(-100i8, 100).saturating_mul_scalar(2), vec2!(-128, 127));
+
+        assert_eq!(a.overflowing_add(b), (vec3!(4, 20, 1), vec3!(true, false, false)));
+        assert_eq!(a.overflowing_sub_scalar(1), (vec3!(249, 9, 255), vec3!(false, false, true)));
+        assert_eq!(b.overflowing_mul(a), (vec3!(196, 100, 0), vec3!(true, false, false)));
+    }
+
+    #[test]
+    fn overflow_newtypes() {
+        use core::num::{Wrapping, Saturating};
+
+        let w = vec2!(Wrapping(250u8), Wrapping(3));
+        assert_eq!(w + vec2!(Wrapping(10), Wrapping(4)), vec2!(Wrapping(4), Wrapping(7)));
+        assert_eq!(w * Wrapping(2), vec2!(Wrapping(244), Wrapping(6)));
+
+        let mut s = vec3!(Saturating(250u8), Saturating(3), Saturating(0));
+        s += vec3!(Saturating(10), Saturating(4), Saturating(0));
+        assert_eq!(s, vec3!(Saturating(255), Saturating(7), Saturating(0)));
+        assert_eq!(s - Saturating(5), vec3!(Saturating(250), Saturating(2), Saturating(0)));
+    }
+
+    #[test]
+    fn integer_functions() {
+        let (a, b) = (vec3!(-7, 7, -8), vec3!(4, 4, 4));
+        assert_eq!(a.div_euc(b), vec3!(-2, 1, -2));
+        assert_eq!(a.rem_euc(b), vec3!(1, 3, 0));
+        assert_eq!(a.div_rem_euc(b), (a.div_euc(b), a.rem_euc(b)));
+        assert_eq!(a.div_ceil(b), vec3!(-1, 2, -2));
+        assert_eq!(a.is_multiple_of(b), vec3!(false, false, true));
+
+        assert_eq!(vec2!(12, 9).gcd(vec2!(18, 6)), vec2!(6, 3));
+        assert_eq!(vec2!(4, 9).lcm(vec2!(6, 6)), vec2!(12, 18));
+        assert_eq!(vec3!(2, -3, 10).pow(3), vec3!(8, -27, 1000));
+
+        assert_eq!(vec2!(7, 9).checked_div(vec2!(2, 3)), Some(vec2!(3, 3)));
+        assert_eq!(vec2!(7, 9).checked_div(vec2!(2, 0)), None);
+        assert_eq!(vec2!(-128i8, 1).checked_div(vec2!(-1, 1)), None);
+        assert_eq!(vec2!(7, 9).checked_rem(vec2!(2, 4)), Some(vec2!(1, 1)));
+        assert_eq!(vec2!(7, 9).checked_rem(vec2!(0, 4)), None);
+
+        let v = vec4!(0u8, 1, 12, 128);
+        assert_eq!(v.is_power_of_two(), vec4!(false, true, false, true));
+        assert_eq!(v.next_power_of_two(), vec4!(1, 1, 16, 128));
+        assert_eq!(v.leading_zeros(), vec4!(8, 7, 4, 0));
+        assert_eq!(v.trailing_zeros(), vec4!(8, 0, 2, 7));
+
+        fn chunk_local<V>(p: V, size: V) -> (V, V)
+            where V: VecInt + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, V::Item: math::Integer
+        {
+            p.div_rem_euc(size)
+        }
+        assert_eq!(chunk_local(vec2!(-1, 17), vec2!(16, 16)), (vec2!(-1, 1), vec2!(15, 1)));
+        assert_eq!(VecN::from_array([3u16, 5, 7, 64, 65]).next_power_of_two(), VecN::from_array([4, 8, 8, 64, 128]));
+    }
+
+    #[test]
+    fn transcendental_functions() {
+        let v = vec3!(1.0f64, 4.0, 9.0);
+        assert_eq!(v.sqrt(), vec3!(1.0, 2.0, 3.0));
+        assert_eq!(v.powi(2), vec3!(1.0, 16.0, 81.0));
+        assert_eq!(v.powf(0.5), v.sqrt());
+        assert_eq!(vec2!(0.0f64, 3.0).exp2(), vec2!(1.0, 8.0));
+        assert_eq!(vec2!(0.0f64, 1.0).exp().ln(), vec2!(0.0, 1.0));
+        assert_eq!(vec2!(8.0f64, 1000.0).log2().x, 3.0);
+        assert_eq!(vec2!(8.0f64, 1000.0).log10().y, 3.0);
+        assert_eq!(vec3!(-1.5f64, 1.5, 2.9).trunc(), vec3!(-1.0, 1.0, 2.0));
+
+        let a = vec2!(0.0f64, 0.5);
+        assert_vec_approx_eq!(a.sin().asin(), a);
+        assert_vec_approx_eq!(a.cos().acos(), a);
+        assert_vec_approx_eq!(a.tan().atan(), a);
+        assert_eq!(a.sinh().x, 0.0);
+        assert_eq!(a.cosh().x, 1.0);
+        assert_eq!(a.tanh().x, 0.0);
+        assert_eq!(vec2!(1.0f64, -1.0).atan2(vec2!(0.0, 0.0)), vec2!(core::f64::consts::FRAC_PI_2, -core::f64::consts::FRAC_PI_2));
+
+        let v = vec4!(f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 1.0);
+        assert_eq!(v.is_nan(), vec4!(true, false, false, false));
+        assert_eq!(v.is_finite(), vec4!(false, false, false, true));
+        assert_eq!(v.is_infinite(), vec4!(false, true, true, false));
+
+        fn finite_roots<V>(v: V) -> (V, <V as VecMap<bool>>::Output)
+            where V: VecFloat + VecMap<bool> + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, V::Item: math::Float
+        {
+            (v.sqrt(), v.sqrt().is_finite())
+        }
+        assert_eq!(finite_roots(vec2!(4.0f32, -1.0)).1, vec2!(true, false));
+        assert_eq!(finite_roots(VecN::from_array([9.0f64; 5])).0, VecN::splat(3.0));
+    }
+
+    #[test]
+    fn interpolation() {
+        let (a, b) = (vec2!(0.0, 10.0), vec2!(4.0, 20.0));
+        assert_eq!(a.lerp(b, 0.25), vec2!(1.0, 12.5));
+        assert_eq!(a.mix(b, vec2!(0.5, 1.0)), vec2!(2.0, 20.0));
+        assert_eq!(vec2!(1.0, 15.0).inverse_lerp(a, b), vec2!(0.25, 0.5));
+        assert_eq!(vec2!(2.0, 15.0).remap(a, b, vec2!(0.0, 0.0), vec2!(1.0, 100.0)), vec2!(0.5, 50.0));
+
+        let edge = vec3!(0.5, 0.5, 0.5);
+        assert_eq!(vec3!(0.2, 0.5, 0.8).step(edge), vec3!(0.0, 1.0, 1.0));
+        let (e0, e1) = (vec3!(0.0, 0.0, 0.0), vec3!(1.0, 1.0, 1.0));
+        assert_eq!(vec3!(-1.0, 0.5, 2.0).smoothstep(e0, e1), vec3!(0.0, 0.5, 1.0));
+        assert_eq!(vec3!(-1.0, 0.5, 2.0).smootherstep(e0, e1), vec3!(0.0, 0.5, 1.0));
+        assert_eq!(vec1!(0.25).smoothstep(vec1!(0.0), vec1!(1.0)), vec1!(0.15625));
+
+        let v = vec4!(-1.5, 0.25, 2.75, -0.0);
+        assert_eq!(v.fract(), vec4!(0.5, 0.25, 0.75, 0.0));
+        assert_eq!(v.saturate(), vec4!(0.0, 0.25, 1.0, 0.0));
+        assert_eq!(v.clamp_components(vec4!(-1.0; 4), vec4!(1.0; 4)), vec4!(-1.0, 0.25, 1.0, 0.0));
+        assert_eq!(v.min_components(vec4!(0.0; 4)), vec4!(-1.5, 0.0, 0.0, -0.0));
+        assert_eq!(v.max_components(vec4!(0.0; 4)), vec4!(0.0, 0.25, 2.75, 0.0));
+        assert_eq!(v.abs(), vec4!(1.5, 0.25, 2.75, 0.0));
+        assert_eq!(v.signum(), vec4!(-1.0, 1.0, 1.0, -1.0));
+        assert_eq!(v.mul_add(vec4!(2.0; 4), vec4!(1.0; 4)), vec4!(-2.0, 1.5, 6.5, 1.0));
+        assert_eq!(vec2!(4.0, 0.5).recip(), vec2!(0.25, 2.0));
+    }
+
+    #[test]
+    fn cross_product() {
+        let (x, y, z) = (vec3!(1.0, 0.0, 0.0), vec3!(0.0, 1.0, 0.0), vec3!(0.0, 0.0, 1.0));
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(x), -z);
+        assert_eq!(vec3!(2, 3, 4).cross(vec3!(5, 6, 7)), vec3!(-3, 6, -3));
+
+        assert_eq!(vec2!(1, 0).perp_dot(vec2!(0, 1)), 1);
+        assert_eq!(vec2!(2, 3).cross(vec2!(4, 5)), -2);
+    }
+
+    #[test]
+    fn vector_geometry() {
+        let v = vec2!(3.0, 4.0);
+        assert_eq!(v.length_squared(), 25.0);
+        assert_eq!(v.distance(vec2!(0.0, 0.0)), 5.0);
+        assert_eq!(vec3!(1, 2, 3).distance_squared(vec3!(2, 4, 6)), 14);
+
+        assert_eq!(vec2!(1.0, -1.0).reflect(vec2!(0.0, 1.0)), vec2!(1.0, 1.0));
+        assert_eq!(v.project_onto(vec2!(2.0, 0.0)), vec2!(3.0, 0.0));
+        assert_eq!(v.reject_from(vec2!(2.0, 0.0)), vec2!(0.0, 4.0));
+
+        let d = vec2!(1.0, -1.0).norm();
+        assert_eq!(d.refract(vec2!(0.0, 1.0), 1.0), Some(d));
+        assert_eq!(d.refract(vec2!(0.0, 1.0), 1.5), None);
+
+        let (x, y) = (vec2!(1.0f32, 0.0), vec2!(0.0f32, 2.0));
+        assert!(x.angle_between(y).approx_eq(&core::f32::consts::FRAC_PI_2));
+        assert!(x.signed_angle(y).approx_eq(&core::f32::consts::FRAC_PI_2));
+        assert!(y.signed_angle(x).approx_eq(&-core::f32::consts::FRAC_PI_2));
+
+        let (x, y, z) = (vec3!(1.0f32, 0.0, 0.0), vec3!(0.0f32, 1.0, 0.0), vec3!(0.0f32, 0.0, 1.0));
+        assert!(x.signed_angle_around(y, z).approx_eq(&core::f32::consts::FRAC_PI_2));
+        assert!(x.signed_angle_around(y, -z).approx_eq(&-core::f32::consts::FRAC_PI_2));
+
+        fn bounce<V>(v: V, normal: V, target: V) -> (V, V::Item)
+            where V: VecFloat + VecDot + Copy + core::ops::Sub<Output=V> + core::ops::Mul<V::Item, Output=V>, V::Item: math::Float
+        {
+            (v.reflect(normal), v.distance(target) + v.angle_between(target))
+        }
+        assert_eq!(bounce(vec2!(1.0f32, -1.0), vec2!(0.0, 1.0), vec2!(1.0, 1.0)), (vec2!(1.0, 1.0), 2.0 + core::f32::consts::FRAC_PI_2));
+        let (r, _) = bounce(VecN::from_array([1.0f64, -1.0, 0.0, 2.0]), VecN::from_array([0.0, 1.0, 0.0, 0.0]), VecN::splat(1.0));
+        assert_eq!(r, VecN::from_array([1.0, 1.0, 0.0, 2.0]));
     }
 
     fn length_of<V: VecFloat>(vec: V) -> V::Item where V::Item: math::Float {
@@ -390,6 +1033,294 @@ mod tests {
         let _ = length_of(v3);
     }
 
+    #[test]
+    fn named_fields() {
+        let mut v = vec4!(1, 2, 3, 4);
+        assert_eq!((v.x, v.y, v.z, v.w), (1, 2, 3, 4));
+
+        v.y = 7;
+        v.w += 1;
+        assert_eq!(v, vec4!(1, 7, 3, 5));
+        assert_eq!(v.elements(), [1, 7, 3, 5]);
+        assert_eq!(vec1!(9).x, 9);
+    }
+
+    #[test]
+    fn high_dimensional() {
+        let a = VecN::from_array([1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let b = VecN::from_array([1.0f32; 8]);
+
+        assert_eq!((a + b) * 2.0 - a, VecN::from_array([3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]));
+        assert_eq!(a.dot(b), 36.0);
+        assert_eq!(b.length(), 8.0f32.sqrt());
+        assert_eq!(a.cmp_gt(b * 4.0).elements(), [false, false, false, false, true, true, true, true]);
+        assert_eq!(a.cast::<u8>(), Some(VecN::from_array([1, 2, 3, 4, 5, 6, 7, 8])));
+        assert_eq!(format!("{:?}", VecN::from_array([1, 2, 3, 4, 5])), "(0: 1, 1: 2, 2: 3, 3: 4, 4: 5)");
+        assert_eq!(format!("{:?}", vec3!(1, 2, 3)), "(x: 1, y: 2, z: 3)");
+    }
+
+    #[test]
+    fn aabb_queries() {
+        let a = Aabb::from_points([vec3!(1.0, 4.0, 2.0), vec3!(-1.0, 0.0, 3.0), vec3!(0.0, 2.0, -2.0)]).unwrap();
+        assert_eq!(a, Aabb::new(vec3!(-1.0, 0.0, -2.0), vec3!(1.0, 4.0, 3.0)));
+        assert_eq!(a.center(), vec3!(0.0, 2.0, 0.5));
+        assert_eq!(a.extent(), vec3!(1.0, 2.0, 2.5));
+        assert_eq!(a.volume(), 40.0);
+        assert_eq!(a.surface_area(), 2.0 * (8.0 + 20.0 + 10.0));
+        assert_eq!(Aabb3f::from_center_extent(a.center(), a.extent()), a);
+        assert_eq!(Aabb::<Vec3f>::from_points([]), None);
+
+        let b = Aabb::new(vec3!(0.0, 1.0, 1.0), vec3!(5.0, 5.0, 5.0));
+        assert!(a.intersects(b) && b.intersects(a));
+        assert_eq!(a.intersection(b), Some(Aabb::new(vec3!(0.0, 1.0, 1.0), vec3!(1.0, 4.0, 3.0))));
+        assert_eq!(a.union(b), Aabb::new(vec3!(-1.0, 0.0, -2.0), vec3!(5.0, 5.0, 5.0)));
+        assert!(a.union(b).contains_aabb(a) && !a.contains_aabb(b));
+        assert!(a.contains_point(vec3!(1.0, 4.0, 3.0)) && !a.contains_point(vec3!(1.5, 0.0, 0.0)));
+        assert_eq!(a.intersection(b.expand(-0.5).shrink(1.0)), None);
+        assert_eq!(a.expand(1.0).shrink(1.0), a);
+
+        assert_eq!(a.closest_point(vec3!(3.0, 2.0, -5.0)), vec3!(1.0, 2.0, -2.0));
+        assert_eq!(a.signed_distance(vec3!(4.0, 2.0, 0.5)), 3.0);
+        assert_eq!(a.signed_distance(vec3!(4.0, 8.0, 0.5)), 5.0);
+        assert_eq!(a.signed_distance(a.center()), -1.0);
+
+        let r = Aabb2::new(vec2!(0.0, 0.0), vec2!(2.0, 3.0));
+        assert_eq!(r.area(), 6.0);
+    }
+
+    #[test]
+    fn aabb_lattice_points() {
+        let a = Aabb::new(vec3!(0, 0, 0), vec3!(1, 2, 3));
+        assert_eq!(a.points().count(), 2 * 3 * 4);
+        assert!(a.points().all(|p| a.contains_point(p)));
+
+        let mut points = Aabb2i::new(vec2!(-1, 5), vec2!(0, 6)).into_iter();
+        assert_eq!(points.next(), Some(vec2!(-1, 5)));
+        assert_eq!(points.next(), Some(vec2!(0, 5)));
+        assert_eq!(points.next(), Some(vec2!(-1, 6)));
+        assert_eq!(points.next(), Some(vec2!(0, 6)));
+        assert_eq!(points.next(), None);
+
+        assert_eq!(Aabb::new(vec2!(1, 1), vec2!(0, 3)).points().count(), 0);
+        assert_eq!(Aabb::new(vec1!(4u8), vec1!(255)).points().count(), 252);
+    }
+
+    #[test]
+    fn ray_intersection() {
+        let r = Ray::new(vec3!(0.0, 0.0, -5.0), vec3!(0.0, 0.0, 2.0));
+        assert_eq!(r.dir, vec3!(0.0, 0.0, 1.0));
+
+        let hit = r.intersect_sphere(vec3!(0.0, 0.0, 0.0), 1.0).unwrap();
+        assert_eq!((hit.distance, hit.point, hit.normal), (4.0, vec3!(0.0, 0.0, -1.0), vec3!(0.0, 0.0, -1.0)));
+        assert_eq!(Ray::new(vec3!(0.0, 0.0, 0.0), vec3!(1.0, 0.0, 0.0)).intersect_sphere(vec3!(0.0, 0.0, 0.0), 2.0).unwrap().distance, 2.0);
+        assert!(r.intersect_sphere(vec3!(0.0, 3.0, 0.0), 1.0).is_none());
+
+        let hit = r.intersect_plane(vec3!(0.0, 0.0, 1.0), 1.0).unwrap();
+        assert_eq!((hit.distance, hit.normal), (6.0, vec3!(0.0, 0.0, -1.0)));
+        assert!(r.intersect_plane(vec3!(1.0, 0.0, 0.0), 1.0).is_none());
+
+        let b = Aabb::new(vec3!(-1.0, -1.0, -1.0), vec3!(1.0, 1.0, 1.0));
+        let hit = r.intersect_aabb(b).unwrap();
+        assert_eq!((hit.distance, hit.point, hit.normal), (4.0, vec3!(0.0, 0.0, -1.0), vec3!(0.0, 0.0, -1.0)));
+        let hit = Ray3f::new(vec3!(0.0, 0.0, 0.0), vec3!(0.0, -1.0, 0.0)).intersect_aabb(b).unwrap();
+        assert_eq!((hit.distance, hit.normal), (1.0, vec3!(0.0, -1.0, 0.0)));
+        assert!(Ray::new(vec3!(0.0, 2.0, -5.0), vec3!(0.0, 0.0, 1.0)).intersect_aabb(b).is_none());
+
+        let hit = r.intersect_triangle(vec3!(-1.0, -1.0, 0.0), vec3!(1.0, -1.0, 0.0), vec3!(0.0, 1.0, 0.0)).unwrap();
+        assert_eq!((hit.distance, hit.point, hit.normal), (5.0, vec3!(0.0, 0.0, 0.0), vec3!(0.0, 0.0, -1.0)));
+        assert!(r.intersect_triangle(vec3!(1.0, 1.0, 0.0), vec3!(2.0, 1.0, 0.0), vec3!(1.0, 2.0, 0.0)).is_none());
+        let small = Ray3::new(vec3!(0.0f32, 0.0, -1.0), vec3!(0.0, 0.0, 1.0));
+        assert!(small.intersect_triangle(vec3!(-1e-4, -1e-4, 0.0), vec3!(1e-4, -1e-4, 0.0), vec3!(0.0, 1e-4, 0.0)).is_some());
+        assert!(r.intersect_triangle(vec3!(0.0, 0.0, 0.0), vec3!(0.0, 0.0, 1.0), vec3!(0.0, 1.0, 0.0)).is_none());
+
+        let r = Ray2::new(vec2!(-3.0, 0.5), vec2!(1.0, 0.0));
+        let hit = r.intersect_aabb(Aabb::new(vec2!(0.0, 0.0), vec2!(1.0, 1.0))).unwrap();
+        assert_eq!((hit.distance, hit.point, hit.normal), (3.0, vec2!(0.0, 0.5), vec2!(-1.0, 0.0)));
+    }
+
+    #[test]
+    fn segment_intersection() {
+        let s = Segment::new(vec3!(0.0, 0.0, -5.0), vec3!(0.0, 0.0, -3.0));
+        assert_eq!(s.length(), 2.0);
+        assert!(s.intersect_sphere(vec3!(0.0, 0.0, 0.0), 1.0).is_none());
+        assert_eq!(s.intersect_sphere(vec3!(0.0, 0.0, 0.0), 4.0).unwrap().point, vec3!(0.0, 0.0, -4.0));
+        assert!(s.intersect_plane(vec3!(0.0, 0.0, 1.0), 0.0).is_none());
+        assert_eq!(Segment2f::new(vec2!(0.0, 0.0), vec2!(0.0, 4.0)).intersect_plane(vec2!(0.0, 2.0), 1.0).unwrap().distance, 1.0);
+    }
+
+    #[test]
+    fn geometry_primitives() {
+        use approx::ApproxEq;
+
+        let plane = Plane3::new(vec3!(0.0f32, 2.0, 0.0), 2.0);
+        assert_eq!(plane.d, 1.0);
+        assert_eq!(plane.signed_distance(vec3!(5.0, 3.0, 1.0)), 2.0);
+        assert_eq!(plane.flip().signed_distance(vec3!(5.0, 3.0, 1.0)), -2.0);
+        assert_eq!(plane.closest_point(vec3!(5.0, -3.0, 1.0)), vec3!(5.0, 1.0, 1.0));
+        assert_eq!(Plane3::from_points(vec3!(0.0f32, 0.0, 1.0), vec3!(1.0, 0.0, 1.0), vec3!(0.0, 1.0, 1.0)), Some(Plane3::new(vec3!(0.0, 0.0, 1.0), 1.0)));
+        assert_eq!(Plane3::from_points(vec3!(0.0f32, 0.0, 0.0), vec3!(1.0, 1.0, 1.0), vec3!(2.0, 2.0, 2.0)), None);
+        assert_eq!(plane.intersect_ray(Ray::new(vec3!(0.0, 5.0, 0.0), vec3!(0.0, -1.0, 0.0))).unwrap().distance, 4.0);
+
+        let sphere = Sphere::new(vec3!(1.0f32, 0.0, 0.0), 2.0);
+        assert_eq!(sphere.signed_distance(vec3!(1.0, 5.0, 0.0)), 3.0);
+        assert_eq!(sphere.signed_distance(vec3!(1.0, 0.0, 0.0)), -2.0);
+        assert_eq!(sphere.closest_point(vec3!(1.0, 0.0, -7.0)), vec3!(1.0, 0.0, -2.0));
+        assert_eq!(sphere.closest_point(sphere.center), vec3!(3.0, 0.0, 0.0));
+        assert_eq!(sphere.normal_at(vec3!(1.0, -4.0, 0.0)), vec3!(0.0, -1.0, 0.0));
+        assert!(sphere.contains_point(vec3!(2.0, 1.0, 1.0)));
+        assert!(!sphere.contains_point(vec3!(3.0, 1.0, 1.0)));
+        assert!(sphere.volume().approx_eq(&(32.0 / 3.0 * core::f32::consts::PI)));
+        assert!(sphere.surface_area().approx_eq(&(16.0 * core::f32::consts::PI)));
+
+        let circle = Circle::new(vec2!(0.0f64, 0.0), 0.5);
+        assert_eq!(circle.signed_distance(vec2!(3.0, 4.0)), 4.5);
+        assert_eq!(circle.closest_point(vec2!(3.0, 4.0)), vec2!(0.3, 0.4));
+        assert!(circle.area().approx_eq(&(0.25 * core::f64::consts::PI)));
+        assert!(circle.circumference().approx_eq(&core::f64::consts::PI));
+        assert_eq!(circle.intersect_ray(Ray::new(vec2!(-2.0, 0.0), vec2!(1.0, 0.0))).unwrap().point, vec2!(-0.5, 0.0));
+
+        let capsule = Capsule::new(vec3!(0.0f64, 0.0, 0.0), vec3!(0.0, 4.0, 0.0), 1.0);
+        assert_eq!(capsule.signed_distance(vec3!(3.0, 2.0, 0.0)), 2.0);
+        assert_eq!(capsule.signed_distance(vec3!(0.0, 7.0, 0.0)), 2.0);
+        assert_eq!(capsule.signed_distance(vec3!(0.0, 1.0, 0.0)), -1.0);
+        assert_eq!(capsule.closest_point(vec3!(0.0, -3.0, 0.0)), vec3!(0.0, -1.0, 0.0));
+        assert_eq!(capsule.normal_at(vec3!(0.0, 2.0, -9.0)), vec3!(0.0, 0.0, -1.0));
+        assert!(capsule.contains_point(vec3!(0.5, 4.5, 0.0)));
+        assert!(capsule.volume().approx_eq(&(16.0 / 3.0 * core::f64::consts::PI)));
+        assert!(capsule.surface_area().approx_eq(&(12.0 * core::f64::consts::PI)));
+    }
+
+    #[test]
+    fn oriented_bounding_boxes() {
+        use approx::ApproxEq;
+
+        let (c, s) = (core::f64::consts::FRAC_1_SQRT_2, core::f64::consts::FRAC_1_SQRT_2);
+        let rotated = Obb3::from_axes(vec3!(2.2, 0.0, 0.0), vec3!(1.0, 1.0, 1.0), [vec3!(c, s, 0.0), vec3!(-s, c, 0.0), vec3!(0.0, 0.0, 1.0)]);
+        assert!(rotated.contains_point(vec3!(3.0, 0.0, 0.5)));
+        assert!(!rotated.contains_point(vec3!(3.0, 0.8, 0.0)));
+        assert_vec_approx_eq!(rotated.closest_point(vec3!(5.0, 0.0, 3.0)), vec3!(2.2 + 2.0 * c, 0.0, 1.0), epsilon = 1e-9);
+        assert_vec_approx_eq!(rotated.corners()[0], vec3!(2.2, -2.0 * c, -1.0), epsilon = 1e-9);
+        assert!(rotated.volume().approx_eq(&8.0));
+        assert_vec_approx_eq!(rotated.aabb().extent(), vec3!(2.0 * c, 2.0 * c, 1.0));
+
+        let unit = Obb3::from_aabb(Aabb::new(vec3!(-1.0, -1.0, -1.0), vec3!(1.0, 1.0, 1.0)));
+        let shifted = Obb3::new(vec3!(1.5, 0.2, 0.0), vec3!(1.0, 1.0, 1.0), Mat3::identity());
+        assert_eq!(unit.overlap_obb(shifted), Some(Penetration { depth: 0.5, axis: vec3!(1.0, 0.0, 0.0) }));
+        assert_eq!(shifted.overlap_obb(unit), Some(Penetration { depth: 0.5, axis: vec3!(-1.0, 0.0, 0.0) }));
+        assert_eq!(unit.overlap_aabb(Aabb::new(vec3!(-0.5, -3.0, -0.5), vec3!(0.5, -0.9, 0.5))).map(|p| p.axis), Some(vec3!(0.0, -1.0, 0.0)));
+        assert_eq!(unit.overlap_aabb(Aabb::new(vec3!(1.5, 1.5, 1.5), vec3!(2.0, 2.0, 2.0))), None);
+
+        let pen = unit.overlap_obb(rotated).unwrap();
+        assert!(pen.depth.abs_diff_eq(&(1.0 - 2.2 + 2.0 * c), 1e-9));
+        assert_vec_approx_eq!(pen.axis, vec3!(1.0, 0.0, 0.0));
+        assert_eq!(unit.overlap_obb(Obb3 { center: vec3!(2.9, 0.0, 0.0), ..rotated }), None);
+
+        let tri = Triangle::new(vec3!(-0.5, -0.5, 0.8), vec3!(0.5, -0.5, 0.8), vec3!(0.0, 0.5, 0.8));
+        let pen = unit.overlap_triangle(tri).unwrap();
+        assert!(pen.depth.abs_diff_eq(&0.2, 1e-9));
+        assert_eq!(pen.axis, vec3!(0.0, 0.0, 1.0));
+        assert_eq!(unit.overlap_triangle(Triangle::new(vec3!(2.5, 0.0, 0.0), vec3!(0.0, 2.5, 0.0), vec3!(2.5, 2.5, 0.0))), None);
+
+        // A small triangle just beyond a corner of the box, facing the corner
+        let corner = Obb3::from_aabb(Aabb::new(vec3!(0.0f32, 0.0, 0.0), vec3!(1.0, 1.0, 1.0)));
+        let (k, d) = (0.0037, 1.0 + 0.001 / 3.0f32.sqrt());
+        let small = Triangle::new(vec3!(d + k, d - k, d), vec3!(d, d + k, d - k), vec3!(d - k, d, d + k));
+        assert_eq!(corner.overlap_triangle(small), None);
+
+        let (c, s) = (0.5f64.cos(), 0.5f64.sin());
+        let original = Obb3::from_axes(vec3!(1.0, -2.0, 3.0), vec3!(3.0, 1.0, 0.5), [vec3!(c, s, 0.0), vec3!(-s, c, 0.0), vec3!(0.0, 0.0, 1.0)]);
+        let fitted = Obb3::from_points(&original.corners()[..]).unwrap();
+        assert_vec_approx_eq!(fitted.center, original.center, epsilon = 1e-9);
+        assert!(fitted.volume().abs_diff_eq(&original.volume(), 1e-9));
+        assert!(fitted.half_extents.elements().iter().zip([3.0, 1.0, 0.5].iter()).all(|(a, b)| a.abs_diff_eq(b, 1e-9)));
+        assert!(fitted.orientation.determinant().approx_eq(&1.0));
+        assert_eq!(Obb3::<f32>::from_points(core::iter::empty::<Vec3<f32>>()), None);
+    }
+
+    #[test]
+    fn triangle_queries() {
+        use approx::ApproxEq;
+
+        let tri = Triangle::new(vec2!(0.0f64, 0.0), vec2!(4.0, 0.0), vec2!(0.0, 3.0));
+        assert_eq!(tri.area(), 6.0);
+        assert_eq!(tri.perimeter(), 12.0);
+        assert_vec_approx_eq!(tri.centroid(), vec2!(4.0 / 3.0, 1.0));
+        assert_vec_approx_eq!(tri.circumcenter().unwrap(), vec2!(2.0, 1.5));
+        assert_vec_approx_eq!(tri.incenter(), vec2!(1.0, 1.0));
+        assert_eq!(tri.barycentric(vec2!(1.0, 1.0)), Some(vec3!(5.0 / 12.0, 0.25, 1.0 / 3.0)));
+        assert_vec_approx_eq!(tri.from_barycentric(vec3!(0.5, 0.25, 0.25)), vec2!(1.0, 0.75));
+        assert!(tri.contains_point(vec2!(1.0, 1.0)));
+        assert!(!tri.contains_point(vec2!(3.0, 3.0)));
+        assert!(tri.signed_distance(vec2!(1.0, 1.0)).approx_eq(&-1.0));
+        assert_eq!(tri.signed_distance(vec2!(2.0, -2.0)), 2.0);
+
+        assert_eq!(tri.closest_point(vec2!(-1.0, -1.0)), vec2!(0.0, 0.0));
+        assert_eq!(tri.closest_point(vec2!(6.0, -1.0)), vec2!(4.0, 0.0));
+        assert_eq!(tri.closest_point(vec2!(2.0, -5.0)), vec2!(2.0, 0.0));
+        assert_eq!(tri.closest_point(vec2!(-2.0, 1.0)), vec2!(0.0, 1.0));
+        assert_vec_approx_eq!(tri.closest_point(vec2!(5.0, 5.5)), vec2!(2.0, 1.5));
+        assert_eq!(tri.closest_point(vec2!(1.0, 1.0)), vec2!(1.0, 1.0));
+
+        let degenerate = Triangle::new(vec2!(0.0f64, 0.0), vec2!(1.0, 1.0), vec2!(2.0, 2.0));
+        assert_eq!(degenerate.barycentric(vec2!(1.0, 0.0)), None);
+        assert_eq!(degenerate.circumcenter(), None);
+
+        let tri = Triangle::new(vec3!(0.0f32, 0.0, 1.0), vec3!(2.0, 0.0, 1.0), vec3!(0.0, 2.0, 1.0));
+        assert_eq!(tri.normal(), vec3!(0.0, 0.0, 1.0));
+        assert_eq!(tri.plane(), Some(Plane3::new(vec3!(0.0, 0.0, 1.0), 1.0)));
+        assert_eq!(tri.closest_point(vec3!(0.5, 0.5, 4.0)), vec3!(0.5, 0.5, 1.0));
+        assert_eq!(tri.signed_distance(vec3!(0.5, 0.5, 4.0)), 3.0);
+        assert_eq!(tri.signed_distance(vec3!(0.5, 0.5, -4.0)), -5.0);
+        let line = Triangle::new(vec3!(0.0, 0.0, 0.0), vec3!(1.0, 0.0, 0.0), vec3!(2.0, 0.0, 0.0));
+        assert_eq!(line.signed_distance(vec3!(1.0, 0.0, -3.0)), 3.0);
+        assert_eq!(tri.barycentric(vec3!(1.0, 0.0, 7.0)), Some(vec3!(0.5, 0.5, 0.0)));
+        assert!(tri.area().approx_eq(&2.0));
+        assert_eq!(tri.intersect_ray(Ray::new(vec3!(0.5, 0.5, 3.0), vec3!(0.0, 0.0, -1.0))).unwrap().distance, 2.0);
+    }
+
+    #[test]
+    fn closest_point_queries() {
+        use geometry::closest;
+
+        let seg = |a: Vec3<f64>, b: Vec3<f64>| Segment::new(a, b);
+        let r = closest::segment_segment(seg(vec3!(0.0, 0.0, 0.0), vec3!(2.0, 0.0, 0.0)), seg(vec3!(1.0, 1.0, -1.0), vec3!(1.0, 1.0, 1.0)));
+        assert_eq!((r.a, r.b, r.distance_squared), (vec3!(1.0, 0.0, 0.0), vec3!(1.0, 1.0, 0.0), 1.0));
+        let r = closest::segment_segment(seg(vec3!(0.0, 0.0, 0.0), vec3!(1.0, 0.0, 0.0)), seg(vec3!(3.0, 1.0, 0.0), vec3!(5.0, 1.0, 0.0)));
+        assert_eq!((r.a, r.b, r.distance_squared), (vec3!(1.0, 0.0, 0.0), vec3!(3.0, 1.0, 0.0), 5.0));
+        let r = closest::segment_segment(seg(vec3!(0.0, 0.0, 0.0), vec3!(4.0, 0.0, 0.0)), seg(vec3!(1.0, 2.0, 0.0), vec3!(3.0, 2.0, 0.0)));
+        assert_eq!(r.distance(), 2.0);
+        let r = closest::segment_segment(seg(vec3!(1.0, 5.0, 0.0), vec3!(1.0, 5.0, 0.0)), seg(vec3!(0.0, 0.0, 0.0), vec3!(4.0, 0.0, 0.0)));
+        assert_eq!((r.a, r.b), (vec3!(1.0, 5.0, 0.0), vec3!(1.0, 0.0, 0.0)));
+        let small = |a: Vec3<f32>, b: Vec3<f32>| Segment::new(a, b);
+        let r = closest::segment_segment(small(vec3!(0.0, 0.0, 0.0), vec3!(2e-4, 0.0, 0.0)), small(vec3!(1e-4, 1e-4, -1e-4), vec3!(1e-4, 1e-4, 1e-4)));
+        assert_eq!((r.a, r.b), (vec3!(1e-4, 0.0, 0.0), vec3!(1e-4, 1e-4, 0.0)));
+
+        let tri = Triangle::new(vec3!(0.0, 0.0, 0.0), vec3!(4.0, 0.0, 0.0), vec3!(0.0, 4.0, 0.0));
+        let r = closest::point_triangle(vec3!(1.0, 1.0, 3.0), tri);
+        assert_eq!((r.b, r.distance_squared), (vec3!(1.0, 1.0, 0.0), 9.0));
+
+        let r = closest::segment_triangle(seg(vec3!(1.0, 1.0, -1.0), vec3!(1.0, 1.0, 1.0)), tri);
+        assert_eq!((r.a, r.b, r.distance_squared), (vec3!(1.0, 1.0, 0.0), vec3!(1.0, 1.0, 0.0), 0.0));
+        let r = closest::segment_triangle(seg(vec3!(1.0, 1.0, 2.0), vec3!(1.0, 1.0, 5.0)), tri);
+        assert_eq!((r.a, r.b, r.distance_squared), (vec3!(1.0, 1.0, 2.0), vec3!(1.0, 1.0, 0.0), 4.0));
+        let r = closest::segment_triangle(seg(vec3!(-1.0, -1.0, -1.0), vec3!(-1.0, -1.0, 1.0)), tri);
+        assert_eq!((r.a, r.b, r.distance_squared), (vec3!(-1.0, -1.0, 0.0), vec3!(0.0, 0.0, 0.0), 2.0));
+
+        let axes = [vec3!(0.0, 1.0, 0.0), vec3!(-1.0, 0.0, 0.0), vec3!(0.0, 0.0, 1.0)];
+        let r = closest::point_obb(vec3!(5.0, 0.5, 0.0), vec3!(1.0, 0.0, 0.0), axes, vec3!(2.0, 1.0, 1.0));
+        assert_eq!((r.b, r.distance_squared), (vec3!(2.0, 0.5, 0.0), 9.0));
+        assert_eq!(closest::point_obb(vec3!(1.5, 0.5, 0.5), vec3!(1.0, 0.0, 0.0), axes, vec3!(2.0, 1.0, 1.0)).distance_squared, 0.0);
+
+        let other = Triangle::new(vec3!(1.0, 1.0, 2.0), vec3!(2.0, 1.0, 2.0), vec3!(1.0, 2.0, 3.0));
+        let r = closest::triangle_triangle(tri, other);
+        assert_eq!((r.b, r.distance_squared), (vec3!(1.0, 1.0, 2.0), 4.0));
+        assert_eq!(closest::triangle_triangle(other, tri), r.swap());
+        let crossing = Triangle::new(vec3!(1.0, 1.0, -1.0), vec3!(1.0, 1.0, 1.0), vec3!(-5.0, 1.0, 0.0));
+        assert_eq!(closest::triangle_triangle(tri, crossing).distance_squared, 0.0);
+        let parallel = Triangle::new(vec3!(1.0, 1.0, 0.5), vec3!(2.0, 1.0, 0.5), vec3!(1.0, 2.0, 0.5));
+        assert_eq!(closest::triangle_triangle(tri, parallel).distance_squared, 0.25);
+    }
+
     #[test]
     fn macros() {
         let _v1_0 = vec1!(7);
@@ -409,6 +1340,171 @@ mod tests {
         let _v4_2 = vec4![7; 4];
     }
 
+    #[test]
+    fn bitwise_operations() {
+        assert_eq!(Vec3u::new(0b1100, 0b1010, 0) & Vec3u::new(0b1010, 0b1010, 1), vec3!(0b1000, 0b1010, 0));
+        assert_eq!(Vec3u::new(0b1100, 0b1010, 0) | Vec3u::new(0b1010, 0b1010, 1), vec3!(0b1110, 0b1010, 1));
+        assert_eq!(Vec3u::new(0b1100, 0b1010, 0) ^ Vec3u::new(0b1010, 0b1010, 1), vec3!(0b0110, 0, 1));
+        assert_eq!(!Vec2::new(0u8, 0xF0), vec2!(0xFF, 0x0F));
+        assert_eq!(Vec4u::new(1, 2, 3, 4) << Vec4u::new(0, 1, 2, 3), vec4!(1, 4, 12, 32));
+        assert_eq!(Vec4u::new(1, 2, 3, 4) >> 1, vec4!(0, 1, 1, 2));
+        assert_eq!(Vec1u::new(0b11) & 0b10, vec1!(0b10));
+
+        let mut v = Vec2u::new(0b01, 0b10);
+        v |= 0b100;
+        v &= Vec2u::new(0b101, 0b100);
+        v ^= 1;
+        v <<= 2;
+        v >>= Vec2u::new(1, 2);
+        assert_eq!(v, vec2!(0b1000, 0b101));
+
+        let mask = vec3!(true, false, true);
+        assert_eq!(mask & !mask, vec3!(false, false, false));
+        assert_eq!(mask | !mask, vec3!(true, true, true));
+        assert!(mask.any() && !mask.all() && !mask.none());
+        assert!((mask ^ mask).none());
+        assert!(Vec4b::new(true, true, true, true).all());
+        assert!(Vec1b::new(false).none());
+    }
+
+    #[test]
+    fn numeric_casts() {
+        use core::convert::TryFrom;
+
+        let f = vec3!(1.5f32, -2.7, 300.0);
+        assert_eq!(f.cast::<i32>(), Some(vec3!(1, -2, 300)));
+        assert_eq!(f.cast::<u8>(), None);
+        assert_eq!(f.try_cast::<u8>().unwrap_err().index(), 1);
+        assert_eq!(f.saturating_cast::<u8>(), vec3!(1, 0, 255));
+        assert_eq!(f.as_cast::<i32>(), vec3!(1, -2, 300));
+        assert_eq!(vec2!(f32::NAN, f32::INFINITY).saturating_cast::<i16>(), vec2!(0, i16::MAX));
+
+        let i = vec4!(1i64, -1, 256, i64::MAX);
+        assert_eq!(i.as_cast::<u8>(), vec4!(1, 255, 0, 255));
+        assert_eq!(i.saturating_cast::<i32>(), vec4!(1, -1, 256, i32::MAX));
+        assert_eq!(Vec4::<i32>::try_from(i).unwrap_err().index(), 3);
+        assert_eq!(Vec2::<u16>::try_from(vec2!(7u64, 9)), Ok(vec2!(7, 9)));
+        assert_eq!(Vec1::<f64>::try_from(vec1!(3u8)), Ok(vec1!(3.0)));
+        assert_eq!(vec1!(true).as_cast::<u8>(), vec1!(1));
+    }
+
+    #[test]
+    fn comparison_masks() {
+        let a = vec4!(1.0, 2.0, 3.0, f32::NAN);
+        let b = vec4!(2.0, 2.0, 2.0, 0.0);
+
+        assert_eq!(a.cmp_lt(b), vec4!(true, false, false, false));
+        assert_eq!(a.cmp_le(b), vec4!(true, true, false, false));
+        assert_eq!(a.cmp_gt(b), vec4!(false, false, true, false));
+        assert_eq!(a.cmp_ge(b), vec4!(false, true, true, false));
+        assert_eq!(a.cmp_eq(b), vec4!(false, true, false, false));
+        assert_eq!(a.cmp_ne(b), vec4!(true, false, true, true));
+
+        // Branchless clamping of a vector to an upper bound
+        let v = vec3!(5, -3, 12);
+        let max = Vec3i::new(10, 10, 10);
+        assert_eq!(Vec3::select(v.cmp_gt(max), max, v), vec3!(5, -3, 10));
+        assert!(v.cmp_lt(max).cmp_eq(vec3!(true, true, false)).all());
+        assert_eq!(Vec1::select(vec1!(true), vec1!(1), vec1!(2)), vec1!(1));
+        assert_eq!(Vec2::select(vec2!(false, true), vec2!(1, 2), vec2!(3, 4)), vec2!(3, 2));
+    }
+
+    #[test]
+    fn swizzle() {
+        let v2 = vec2!(1, 2);
+        let v3 = vec3!(1, 2, 3);
+        let v4 = vec4!(1, 2, 3, 4);
+
+        assert_eq!(vec1!(7).xxx(), vec3!(7, 7, 7));
+        assert_eq!(v2.yx(), vec2!(2, 1));
+        assert_eq!(v2.xyyx(), vec4!(1, 2, 2, 1));
+        assert_eq!(v3.xz(), vec2!(1, 3));
+        assert_eq!(v3.zyx(), vec3!(3, 2, 1));
+        assert_eq!(v3.xyzz(), vec4!(1, 2, 3, 3));
+        assert_eq!(v4.wzyx(), vec4!(4, 3, 2, 1));
+        assert_eq!(v4.ww(), vec2!(4, 4));
+
+        let mut v = v4;
+        v.set_xy(vec2!(5, 6));
+        assert_eq!(v, vec4!(5, 6, 3, 4));
+        v.set_wzx(vec3!(7, 8, 9));
+        assert_eq!(v, vec4!(9, 6, 8, 7));
+        v.set_yxwz(v4);
+        assert_eq!(v, vec4!(2, 1, 4, 3));
+
+        let mut v = v3;
+        v.set_zx(vec2!(0, 9));
+        assert_eq!(v, vec3!(9, 2, 0));
+    }
+
+    #[test]
+    fn matrix_operations() {
+        let m = Mat3i::from_rows(vec3!(1, 2, 3), vec3!(0, 1, 4), vec3!(5, 6, 0));
+
+        assert_eq!(m * Mat3i::identity(), m);
+        assert_eq!(Mat3i::identity() * m, m);
+        assert_eq!(m * vec3!(1, 0, 0), vec3!(1, 0, 5));
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(m.rows()[0], vec3!(1, 2, 3));
+        assert_eq!(m.determinant(), 1);
+
+        assert_eq!(Mat2i::from_rows(vec2!(3, 8), vec2!(4, 6)).determinant(), -14);
+        assert_eq!(Mat4i::from_diagonal(vec4!(1, 2, 3, 4)).determinant(), 24);
+        assert_eq!(Mat4i::identity() * vec4!(1, 2, 3, 4), vec4!(1, 2, 3, 4));
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let m2 = Mat2::from_rows(vec2!(4.0f32, 7.0), vec2!(2.0, 6.0));
+        let m3 = Mat3::from_rows(vec3!(1.0f32, 2.0, 3.0), vec3!(0.0, 1.0, 4.0), vec3!(5.0, 6.0, 0.0));
+        let m4 = Mat4::from_rows(
+            vec4!(2.0f32, 0.0, 1.0, 3.0),
+            vec4!(1.0, 3.0, 0.0, 2.0),
+            vec4!(0.0, 1.0, 4.0, 1.0),
+            vec4!(3.0, 2.0, 1.0, 0.0),
+        );
+
+        assert!((m2 * m2.inverse().unwrap()).abs_diff_eq(&Mat2::identity(), 1e-5));
+        assert!((m2.inverse().unwrap() * m2).abs_diff_eq(&Mat2::identity(), 1e-5));
+        assert!((m3 * m3.inverse().unwrap()).abs_diff_eq(&Mat3::identity(), 1e-5));
+        assert!((m3.inverse().unwrap() * m3).abs_diff_eq(&Mat3::identity(), 1e-5));
+        assert!((m4 * m4.inverse().unwrap()).abs_diff_eq(&Mat4::identity(), 1e-5));
+        assert!((m4.inverse().unwrap() * m4).abs_diff_eq(&Mat4::identity(), 1e-5));
+        assert!(m4.determinant().abs_diff_eq(&-120.0, 1e-3));
+
+        assert_eq!(Mat2f::from_rows(vec2!(1.0, 2.0), vec2!(2.0, 4.0)).inverse(), None);
+        assert_eq!(Mat3f::zero().inverse(), None);
+        assert_eq!(Mat4f::zero().inverse(), None);
+    }
+
+    #[test]
+    fn quaternion_rotation() {
+        let half_pi = ::core::f32::consts::FRAC_PI_2;
+        let x = vec3!(1.0f32, 0.0, 0.0);
+        let y = vec3!(0.0f32, 1.0, 0.0);
+        let z = vec3!(0.0f32, 0.0, 1.0);
+
+        let qz = Quat::from_axis_angle(z, half_pi);
+        let qx = Quat::from_axis_angle(x, half_pi);
+        assert_vec_approx_eq!(qz.rotate(x), y, epsilon = 1e-6);
+        assert_vec_approx_eq!(qz * y, -x, epsilon = 1e-6);
+        assert_vec_approx_eq!((qx * qz) * x, qx * (qz * x), epsilon = 1e-6);
+        assert_vec_approx_eq!((qz * qz.inverse()) * y, y, epsilon = 1e-6);
+        assert_vec_approx_eq!(qz.conjugate() * y, x, epsilon = 1e-6);
+
+        assert_vec_approx_eq!(Quat::from_vectors(x, z) * x, z, epsilon = 1e-6);
+        assert_vec_approx_eq!(Quat::from_vectors(x, -x) * x, -x, epsilon = 1e-6);
+
+        assert_vec_approx_eq!(qz.to_mat3() * x, qz * x, epsilon = 1e-6);
+        assert_vec_approx_eq!(Quat::from_mat3((qx * qz).to_mat3()) * y, (qx * qz) * y, epsilon = 1e-6);
+        assert_vec_approx_eq!(Quat::from(qz.to_vec4()) * x, y, epsilon = 1e-6);
+
+        let quarter = Quat::from_axis_angle(z, half_pi / 2.0);
+        assert_vec_approx_eq!(Quat::identity().slerp(qz, 0.5) * x, quarter * x, epsilon = 1e-6);
+        assert_vec_approx_eq!(Quat::identity().nlerp(qz, 0.5) * x, quarter * x, epsilon = 1e-6);
+        assert_vec_approx_eq!(qz.slerp(qz, 0.5) * x, qz * x, epsilon = 1e-6);
+    }
+
     #[cfg(feature = "serialize")]
     #[test]
     fn serialize() {
@@ -418,6 +1514,18 @@ mod tests {
     #[cfg(feature = "serialize")]
     #[test]
     fn deserialize() {
-        // Nothing yet
+        use serde::Deserialize;
+        use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+
+        let fields = [("z", 3), ("x", 1), ("y", 2)];
+        let v3 = Vec3::<i32>::deserialize(MapDeserializer::<_, Error>::new(fields.iter().cloned()));
+        assert_eq!(v3, Ok(vec3!(1, 2, 3)));
+
+        let missing = [("x", 1), ("y", 2)];
+        assert!(Vec3::<i32>::deserialize(MapDeserializer::<_, Error>::new(missing.iter().cloned())).is_err());
+
+        let items = [1, 2, 3, 4, 5];
+        let v5 = VecN::<i32, 5>::deserialize(SeqDeserializer::<_, Error>::new(items.iter().cloned()));
+        assert_eq!(v5, Ok(VecN::from_array(items)));
     }
 }
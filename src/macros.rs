@@ -70,6 +70,7 @@ macro_rules! vec2 {
 /// let my_vec = vec3!((12, -4, 0));     // Tuple instantiation
 /// let my_vec = vec3!([3.2, 8.9, 7.3]); // Array slice instantiation
 /// let my_vec = vec3!([true; 3]);       // Array copy instantiation
+/// let my_vec = vec3!(vec2!(1, 2), 3);  // Mixed instantiation
 /// ```
 #[macro_export]
 #[allow(unused_macros)]
@@ -82,6 +83,10 @@ macro_rules! vec3 {
         Vec3::from([$arr; 3])
     );
 
+    ($a:expr, $b:expr) => (
+        Vec3::from(($a, $b))
+    );
+
     ($x:expr, $y:expr, $z:expr) => (
         Vec3::new($x, $y, $z)
     );
@@ -99,6 +104,8 @@ macro_rules! vec3 {
 /// let my_vec = vec4!((12, -4, 0, 9));        // Tuple instantiation
 /// let my_vec = vec4!([3.2, 8.9, 7.3, -2.0]); // Array slice instantiation
 /// let my_vec = vec4!([true; 4]);             // Array copy instantiation
+/// let my_vec = vec4!(vec3!(7, 9, 2), 1);     // Mixed instantiation
+/// let my_vec = vec4!(0, vec2!(5, 6), 7);     // Mixed instantiation
 /// ```
 #[macro_export]
 #[allow(unused_macros)]
@@ -111,7 +118,1005 @@ macro_rules! vec4 {
         Vec4::from([$arr; 4])
     );
 
+    ($a:expr, $b:expr) => (
+        Vec4::from(($a, $b))
+    );
+
+    ($a:expr, $b:expr, $c:expr) => (
+        Vec4::from(($a, $b, $c))
+    );
+
     ($x:expr, $y:expr, $z:expr, $w:expr) => (
         Vec4::new($x, $y, $z, $w)
     );
 }
+
+/// Assert that two floating point vectors are approximately equal, printing both vectors and the first offending
+/// component upon failure
+///
+/// By default, components are compared with a relative tolerance *(see `ApproxEq::relative_eq`)*. Giving only an
+/// `epsilon` performs an absolute comparison instead.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate coord;
+/// use coord::prelude::*;
+///
+/// # fn main() {
+/// let v = vec3!(0.1f32, 0.2, 0.3);
+/// assert_vec_approx_eq!(v * 3.0, vec3!(0.3, 0.6, 0.9));                        // Default tolerances
+/// assert_vec_approx_eq!(v * 3.0, vec3!(0.3, 0.6, 0.9), epsilon = 1e-6);        // Absolute tolerance
+/// assert_vec_approx_eq!(v * 3.0, vec3!(0.3, 0.6, 0.9), max_relative = 1e-6);   // Relative tolerance
+/// assert_vec_approx_eq!(v, vec3!(0.1, 0.2, 0.3), epsilon = 0.0, max_relative = 1e-6);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_vec_approx_eq {
+    ($left:expr, $right:expr $(,)*) => ({
+        let (left, right) = (&$left, &$right);
+        let (epsilon, max_relative, _) = $crate::approx::defaults(left);
+        $crate::approx::assert_vec(left, right, "approximately", |a, b| {
+            $crate::approx::ApproxEq::relative_eq(a, b, epsilon, max_relative)
+        });
+    });
+
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)*) => ({
+        let (left, right) = (&$left, &$right);
+        $crate::approx::assert_vec(left, right, "absolutely", |a, b| {
+            $crate::approx::ApproxEq::abs_diff_eq(a, b, $epsilon)
+        });
+    });
+
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)*) => ({
+        let (left, right) = (&$left, &$right);
+        let (epsilon, _, _) = $crate::approx::defaults(left);
+        $crate::approx::assert_vec(left, right, "relatively", |a, b| {
+            $crate::approx::ApproxEq::relative_eq(a, b, epsilon, $max_relative)
+        });
+    });
+
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)*) => ({
+        let (left, right) = (&$left, &$right);
+        $crate::approx::assert_vec(left, right, "relatively", |a, b| {
+            $crate::approx::ApproxEq::relative_eq(a, b, $epsilon, $max_relative)
+        });
+    });
+}
+
+/// Assert that the components of two floating point vectors are no more than a given number of representable values
+/// apart, printing both vectors and the first offending component upon failure
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate coord;
+/// use coord::prelude::*;
+///
+/// # fn main() {
+/// let v = vec2!(0.1f64, 0.7);
+/// assert_vec_ulps_eq!(v * 3.0, vec2!(0.3, 2.1));                             // Default tolerances
+/// assert_vec_ulps_eq!(v * 3.0, vec2!(0.3, 2.1), max_ulps = 2);               // ULPs tolerance
+/// assert_vec_ulps_eq!(v * 3.0, vec2!(0.3, 2.1), epsilon = 0.0, max_ulps = 2);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_vec_ulps_eq {
+    ($left:expr, $right:expr $(,)*) => ({
+        let (left, right) = (&$left, &$right);
+        let (epsilon, _, max_ulps) = $crate::approx::defaults(left);
+        $crate::approx::assert_vec(left, right, "ULPs", |a, b| {
+            $crate::approx::ApproxEq::ulps_eq(a, b, epsilon, max_ulps)
+        });
+    });
+
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)*) => ({
+        let (left, right) = (&$left, &$right);
+        let (epsilon, _, _) = $crate::approx::defaults(left);
+        $crate::approx::assert_vec(left, right, "ULPs", |a, b| {
+            $crate::approx::ApproxEq::ulps_eq(a, b, epsilon, $max_ulps)
+        });
+    });
+
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr $(,)*) => ({
+        let (left, right) = (&$left, &$right);
+        $crate::approx::assert_vec(left, right, "ULPs", |a, b| {
+            $crate::approx::ApproxEq::ulps_eq(a, b, $epsilon, $max_ulps)
+        });
+    });
+}
+
+/// Implements swizzle accessors upon a vector type, each returning a vector of the given output type
+///
+/// Each entry takes the form `name: a b ...;`, where `a b ...` are the components of the vector used to construct the
+/// output in order.
+macro_rules! impl_swizzle_get {
+    ($ty:ident => $out:ident { $($name:ident: $($c:ident)+;)* }) => (
+        impl<T: $crate::VecItem> $ty<T> {
+            $(
+                #[doc = concat!("Returns the `", $(stringify!($c),)+ "` swizzle of the vector")]
+                pub fn $name(&self) -> $out<T> {
+                    $out::new($(self.$c),+)
+                }
+            )*
+        }
+    );
+}
+
+/// Implements swizzle setters upon a vector type, each taking a vector of the given input type
+///
+/// Each entry takes the form `name: a b ...;`, where `a b ...` are the components of the vector that are assigned
+/// the components of the input in order.
+macro_rules! impl_swizzle_set {
+    ($ty:ident => $src:ident { $($name:ident: $($c:ident)+;)* }) => (
+        impl<T: $crate::VecItem> $ty<T> {
+            $(
+                #[doc = concat!("Sets the `", $(stringify!($c),)+ "` components of the vector from the components of `v`")]
+                pub fn $name(&mut self, v: $src<T>) {
+                    swizzle_assign!(self, v; $($c)+);
+                }
+            )*
+        }
+    );
+}
+
+/// Assigns the components of a source vector to the given components of a target vector, in order
+macro_rules! swizzle_assign {
+    ($this:ident, $v:ident; $a:ident $b:ident) => ({
+        $this.$a = $v.x;
+        $this.$b = $v.y;
+    });
+    ($this:ident, $v:ident; $a:ident $b:ident $c:ident) => ({
+        $this.$a = $v.x;
+        $this.$b = $v.y;
+        $this.$c = $v.z;
+    });
+    ($this:ident, $v:ident; $a:ident $b:ident $c:ident $d:ident) => ({
+        $this.$a = $v.x;
+        $this.$b = $v.y;
+        $this.$c = $v.z;
+        $this.$d = $v.w;
+    });
+}
+
+/// Implements the functionality shared by `VecN` and the named vector types upon a vector type
+///
+/// The vector type is given by its name *(followed by `[N]` for `VecN`, whose number of components is generic)*, its
+/// number of components and the `UNIT_*` constants it provides, each as `NAME axis index`. The type must itself provide
+/// the `from_array`, `elements`, `as_slice` and `as_mut_slice` methods upon which everything else is built.
+macro_rules! impl_vec {
+    ($V:ident $([$N:ident])?, $n:tt $units:tt) => (
+        impl_vec_methods!(($V $([$N])?), $n);
+        impl_vec_traits!(($V $([$N])?), $n);
+        impl_vec_math!(($V $([$N])?), $n);
+
+        impl_vec_consts!(($V $([$N])?), $n $units; u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+        impl_vec_consts!(($V $([$N])?), $n $units; f32, f64; 0.0, 1.0);
+
+        impl_vec_try_from!(($V $([$N])?));
+
+        impl_vec_op!(($V $([$N])?); Add, add, AddAssign, add_assign, +);
+        impl_vec_op!(($V $([$N])?); Sub, sub, SubAssign, sub_assign, -);
+        impl_vec_op!(($V $([$N])?); Mul, mul, MulAssign, mul_assign, *);
+        impl_vec_op!(($V $([$N])?); Div, div, DivAssign, div_assign, /);
+        impl_vec_op!(($V $([$N])?); Rem, rem, RemAssign, rem_assign, %);
+        impl_vec_unary_op!(($V $([$N])?); Neg, neg, -);
+
+        impl_vec_scalar_lhs_op!(($V $([$N])?); u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+        impl_vec_op!(($V $([$N])?); BitAnd, bitand, BitAndAssign, bitand_assign, &);
+        impl_vec_op!(($V $([$N])?); BitOr, bitor, BitOrAssign, bitor_assign, |);
+        impl_vec_op!(($V $([$N])?); BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+        impl_vec_op!(($V $([$N])?); Shl, shl, ShlAssign, shl_assign, <<);
+        impl_vec_op!(($V $([$N])?); Shr, shr, ShrAssign, shr_assign, >>);
+        impl_vec_unary_op!(($V $([$N])?); Not, not, !);
+
+        impl_vec_overflow_op!(checked ($V $([$N])?), $n; CheckedAdd, checked_add, checked_add_scalar, "addition");
+        impl_vec_overflow_op!(checked ($V $([$N])?), $n; CheckedSub, checked_sub, checked_sub_scalar, "subtraction");
+        impl_vec_overflow_op!(checked ($V $([$N])?), $n; CheckedMul, checked_mul, checked_mul_scalar, "multiplication");
+        impl_vec_overflow_op!(checked ($V $([$N])?), $n; CheckedDiv, checked_div, checked_div_scalar, "division");
+
+        impl_vec_overflow_op!(wrapping ($V $([$N])?), $n; WrappingAdd, wrapping_add, wrapping_add_scalar, "addition");
+        impl_vec_overflow_op!(wrapping ($V $([$N])?), $n; WrappingSub, wrapping_sub, wrapping_sub_scalar, "subtraction");
+        impl_vec_overflow_op!(wrapping ($V $([$N])?), $n; WrappingMul, wrapping_mul, wrapping_mul_scalar, "multiplication");
+
+        impl_vec_overflow_op!(saturating ($V $([$N])?), $n; SaturatingAdd, saturating_add, saturating_add_scalar, "addition");
+        impl_vec_overflow_op!(saturating ($V $([$N])?), $n; SaturatingSub, saturating_sub, saturating_sub_scalar, "subtraction");
+        impl_vec_overflow_op!(saturating ($V $([$N])?), $n; SaturatingMul, saturating_mul, saturating_mul_scalar, "multiplication");
+
+        impl_vec_overflow_op!(overflowing ($V $([$N])?), $n; OverflowingAdd, overflowing_add, overflowing_add_scalar, "addition");
+        impl_vec_overflow_op!(overflowing ($V $([$N])?), $n; OverflowingSub, overflowing_sub, overflowing_sub_scalar, "subtraction");
+        impl_vec_overflow_op!(overflowing ($V $([$N])?), $n; OverflowingMul, overflowing_mul, overflowing_mul_scalar, "multiplication");
+    );
+}
+
+/// Implements the inherent methods shared by all vector types
+macro_rules! impl_vec_methods {
+    (($V:ident $([$N:ident])?), $n:tt) => (
+        impl<T: $crate::VecItem $(, const $N: usize)?> $V<T $(, $N)?> {
+            #[doc = concat!("Creates a new ", stringify!($V), " with every component set to `v`")]
+            pub const fn splat(v: T) -> Self { Self::from_array([v; $n]) }
+
+            /// Returns an iterator over the elements of the vector
+            pub fn iter(&self) -> $crate::core::slice::Iter<'_, T> { self.as_slice().iter() }
+
+            /// Returns an iterator that allows modifying each element of the vector
+            pub fn iter_mut(&mut self) -> $crate::core::slice::IterMut<'_, T> { self.as_mut_slice().iter_mut() }
+
+            /// Reinterprets a slice of vectors as a slice of their components
+            pub fn flatten_slice(vecs: &[Self]) -> &[T] {
+                // Safe because vectors are `#[repr(C)]` and have exactly the layout of an array of their components
+                unsafe { $crate::core::slice::from_raw_parts(vecs.as_ptr() as *const T, vecs.len() * $n) }
+            }
+
+            /// Reinterprets a mutable slice of vectors as a mutable slice of their components
+            pub fn flatten_slice_mut(vecs: &mut [Self]) -> &mut [T] {
+                // Safe because vectors are `#[repr(C)]` and have exactly the layout of an array of their components
+                unsafe { $crate::core::slice::from_raw_parts_mut(vecs.as_mut_ptr() as *mut T, vecs.len() * $n) }
+            }
+
+            /// Reinterprets a slice of components as a slice of vectors, returning `None` if the length of the slice is
+            /// not a multiple of the number of components in a vector
+            pub fn from_flat_slice(items: &[T]) -> Option<&[Self]> {
+                if $n == 0 || !items.len().is_multiple_of($n) {
+                    return None;
+                }
+                // Safe because vectors are `#[repr(C)]` and have exactly the layout (and alignment) of an array of
+                // their components
+                Some(unsafe { $crate::core::slice::from_raw_parts(items.as_ptr() as *const Self, items.len() / $n) })
+            }
+
+            /// Reinterprets a mutable slice of components as a mutable slice of vectors, returning `None` if the length
+            /// of the slice is not a multiple of the number of components in a vector
+            pub fn from_flat_slice_mut(items: &mut [T]) -> Option<&mut [Self]> {
+                if $n == 0 || !items.len().is_multiple_of($n) {
+                    return None;
+                }
+                // Safe because vectors are `#[repr(C)]` and have exactly the layout (and alignment) of an array of
+                // their components
+                let len = items.len() / $n;
+                Some(unsafe { $crate::core::slice::from_raw_parts_mut(items.as_mut_ptr() as *mut Self, len) })
+            }
+
+            /// Apply an operation to all elements of this vector, returning the result
+            pub fn map<U: $crate::VecItem, F: Fn(T) -> U>(&self, f: F) -> $V<U $(, $N)?> {
+                $V::from_array(self.elements().map(f))
+            }
+
+            /// Converts each element of this vector to another type using its `From` implementation
+            pub fn convert_to<U: $crate::VecItem + From<T>>(&self) -> $V<U $(, $N)?> {
+                self.map(U::from)
+            }
+
+            /// Apply an operation to the elements of this vector pairwise with those of another vector, returning the
+            /// result
+            pub fn zip_map<U, R, F>(&self, other: $V<U $(, $N)?>, f: F) -> $V<R $(, $N)?>
+                where U: $crate::VecItem, R: $crate::VecItem, F: Fn(T, U) -> R {
+                let (a, b) = (self.elements(), other.elements());
+                $V::from_array($crate::core::array::from_fn(|i| f(a[i], b[i])))
+            }
+
+            /// Combines the elements of the vector, in order, into a single value starting from `init`
+            pub fn fold<A, F: FnMut(A, T) -> A>(&self, init: A, f: F) -> A {
+                self.iter().copied().fold(init, f)
+            }
+
+            /// Combines the elements of the vector, in order, into a single value starting from the first element
+            ///
+            /// *Panics if the vector has no elements*
+            pub fn reduce<F: FnMut(T, T) -> T>(&self, f: F) -> T {
+                self.iter().copied().reduce(f).expect("cannot reduce a vector with no elements")
+            }
+
+            /// Compares the vectors component-wise, returning a mask of the components that are equal to those of
+            /// `other`
+            pub fn cmp_eq(&self, other: Self) -> $V<bool $(, $N)?> {
+                self.zip_map(other, |a, b| a == b)
+            }
+
+            /// Compares the vectors component-wise, returning a mask of the components that are not equal to those of
+            /// `other`
+            pub fn cmp_ne(&self, other: Self) -> $V<bool $(, $N)?> {
+                self.zip_map(other, |a, b| a != b)
+            }
+
+            #[doc = concat!("Creates a new ", stringify!($V), " by selecting each component from `a` where `mask` is `true`, and from `b` otherwise")]
+            pub fn select(mask: $V<bool $(, $N)?>, a: Self, b: Self) -> Self {
+                let (mask, a, b) = (mask.elements(), a.elements(), b.elements());
+                $V::from_array($crate::core::array::from_fn(|i| if mask[i] { a[i] } else { b[i] }))
+            }
+
+            /// Casts each component of the vector to another primitive type with the semantics of the `as` operator
+            /// *(i.e: integers wrap and floats truncate)*
+            pub fn as_cast<U: $crate::VecItem + 'static>(&self) -> $V<U $(, $N)?>
+                where T: $crate::num::traits::AsPrimitive<U> {
+                self.map(|e| e.as_())
+            }
+        }
+
+        impl<T: $crate::VecItem + $crate::num::Num $(, const $N: usize)?> $V<T $(, $N)?> {
+            #[doc = concat!("Creates a new ", stringify!($V), " with every component set to zero")]
+            pub fn zero() -> Self { Self::splat(T::zero()) }
+
+            #[doc = concat!("Creates a new ", stringify!($V), " with every component set to one")]
+            pub fn one() -> Self { Self::splat(T::one()) }
+
+            #[doc = concat!("Creates a new ", stringify!($V), " of unit length along the given axis")]
+            ///
+            /// *Panics if `axis` is not less than the number of components in the vector*
+            pub fn unit(axis: usize) -> Self {
+                let mut v = Self::zero();
+                v[axis] = T::one();
+                v
+            }
+        }
+
+        impl<T: $crate::VecItem + PartialOrd $(, const $N: usize)?> $V<T $(, $N)?> {
+            /// Compares the vectors component-wise, returning a mask of the components that are less than those of
+            /// `other`
+            pub fn cmp_lt(&self, other: Self) -> $V<bool $(, $N)?> {
+                self.zip_map(other, |a, b| a < b)
+            }
+
+            /// Compares the vectors component-wise, returning a mask of the components that are less than or equal to
+            /// those of `other`
+            pub fn cmp_le(&self, other: Self) -> $V<bool $(, $N)?> {
+                self.zip_map(other, |a, b| a <= b)
+            }
+
+            /// Compares the vectors component-wise, returning a mask of the components that are greater than those of
+            /// `other`
+            pub fn cmp_gt(&self, other: Self) -> $V<bool $(, $N)?> {
+                self.zip_map(other, |a, b| a > b)
+            }
+
+            /// Compares the vectors component-wise, returning a mask of the components that are greater than or equal
+            /// to those of `other`
+            pub fn cmp_ge(&self, other: Self) -> $V<bool $(, $N)?> {
+                self.zip_map(other, |a, b| a >= b)
+            }
+
+            /// Returns the smallest element of the vector
+            ///
+            /// *Panics if the vector has no elements*
+            pub fn min_element(&self) -> T {
+                self[self.argmin()]
+            }
+
+            /// Returns the largest element of the vector
+            ///
+            /// *Panics if the vector has no elements*
+            pub fn max_element(&self) -> T {
+                self[self.argmax()]
+            }
+
+            /// Returns the index of the smallest element of the vector, preferring the first such element when several
+            /// are equal
+            ///
+            /// *Panics if the vector has no elements*
+            pub fn argmin(&self) -> usize {
+                assert!($n > 0, "vector has no elements");
+                (1..$n).fold(0, |best, i| if self[i] < self[best] { i } else { best })
+            }
+
+            /// Returns the index of the largest element of the vector, preferring the first such element when several
+            /// are equal
+            ///
+            /// *Panics if the vector has no elements*
+            pub fn argmax(&self) -> usize {
+                assert!($n > 0, "vector has no elements");
+                (1..$n).fold(0, |best, i| if self[i] > self[best] { i } else { best })
+            }
+        }
+
+        impl<T: $crate::VecItem + $crate::num::ToPrimitive $(, const $N: usize)?> $V<T $(, $N)?> {
+            /// Casts each component of the vector to another numeric type, returning an error identifying the first
+            /// component that cannot be represented by the new type
+            pub fn try_cast<U>(&self) -> Result<$V<U $(, $N)?>, $crate::cast::CastError>
+                where U: $crate::VecItem + $crate::num::NumCast {
+                let mut data = [U::default(); $n];
+                for (i, (out, item)) in data.iter_mut().zip(self.iter()).enumerate() {
+                    *out = $crate::cast::cast_item(*item, i)?;
+                }
+                Ok($V::from_array(data))
+            }
+
+            /// Casts each component of the vector to another numeric type, returning `None` if any component cannot be
+            /// represented by the new type
+            pub fn cast<U: $crate::VecItem + $crate::num::NumCast>(&self) -> Option<$V<U $(, $N)?>> {
+                self.try_cast().ok()
+            }
+        }
+
+        impl<T $(, const $N: usize)?> $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::ToPrimitive + $crate::num::Zero + PartialOrd {
+            /// Casts each component of the vector to another numeric type, clamping components that cannot be
+            /// represented by the new type to its minimum or maximum value
+            pub fn saturating_cast<U>(&self) -> $V<U $(, $N)?>
+                where U: $crate::VecItem + $crate::num::NumCast + $crate::num::Bounded + $crate::num::Zero {
+                self.map($crate::cast::saturating_cast_item)
+            }
+        }
+
+        impl<$(const $N: usize)?> $V<bool $(, $N)?> {
+            /// A vector with every component set to `false`
+            pub const FALSE: Self = Self::splat(false);
+            /// A vector with every component set to `true`
+            pub const TRUE: Self = Self::splat(true);
+
+            /// Returns `true` if any component of the vector is `true`
+            pub fn any(&self) -> bool {
+                self.iter().any(|e| *e)
+            }
+
+            /// Returns `true` if all components of the vector are `true`
+            pub fn all(&self) -> bool {
+                self.iter().all(|e| *e)
+            }
+
+            /// Returns `true` if no component of the vector is `true`
+            pub fn none(&self) -> bool {
+                !self.any()
+            }
+        }
+    );
+}
+
+/// Implements the standard library traits shared by all vector types
+macro_rules! impl_vec_traits {
+    (($V:ident $([$N:ident])?), $n:tt) => (
+        impl<T: $crate::VecItem $(, const $N: usize)?> $crate::Vector for $V<T $(, $N)?> {
+            type Item = T;
+        }
+
+        impl<T: $crate::VecItem, U: $crate::VecItem $(, const $N: usize)?> $crate::math::VecMap<U> for $V<T $(, $N)?> {
+            type Output = $V<U $(, $N)?>;
+        }
+
+        // Debug and Display traits
+
+        impl<T $(, const $N: usize)?> $crate::core::fmt::Debug for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::fmt::Debug {
+            fn fmt(&self, f: &mut $crate::core::fmt::Formatter) -> $crate::core::fmt::Result {
+                write!(f, "(")?;
+                for (i, item) in self.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    match ["x", "y", "z", "w"].get(i) {
+                        Some(name) if $n <= 4 => write!(f, "{}: {:?}", name, item)?,
+                        _ => write!(f, "{}: {:?}", i, item)?,
+                    }
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl<T $(, const $N: usize)?> $crate::core::fmt::Display for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::fmt::Display {
+            fn fmt(&self, f: &mut $crate::core::fmt::Formatter) -> $crate::core::fmt::Result {
+                write!(f, "(")?;
+                for (i, item) in self.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        // From traits
+
+        impl<T: $crate::VecItem $(, const $N: usize)?> AsRef<[T]> for $V<T $(, $N)?> {
+            fn as_ref(&self) -> &[T] { self.as_slice() }
+        }
+
+        impl<T: $crate::VecItem $(, const $N: usize)?> AsMut<[T]> for $V<T $(, $N)?> {
+            fn as_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+        }
+
+        // Ordering traits
+
+        /// Compares vectors lexicographically *(i.e: by their first differing component)*
+        impl<T: $crate::VecItem + PartialOrd $(, const $N: usize)?> PartialOrd for $V<T $(, $N)?> {
+            fn partial_cmp(&self, other: &Self) -> Option<$crate::core::cmp::Ordering> {
+                self.as_slice().partial_cmp(other.as_slice())
+            }
+        }
+
+        /// Compares vectors lexicographically *(i.e: by their first differing component)*
+        impl<T: $crate::VecItem + Ord $(, const $N: usize)?> Ord for $V<T $(, $N)?> {
+            fn cmp(&self, other: &Self) -> $crate::core::cmp::Ordering {
+                self.as_slice().cmp(other.as_slice())
+            }
+        }
+
+        // Sum and Product traits
+
+        impl<T: $crate::VecItem + $crate::num::Num $(, const $N: usize)?> $crate::core::iter::Sum for $V<T $(, $N)?> {
+            fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |a, b| a + b)
+            }
+        }
+
+        impl<'a, T $(, const $N: usize)?> $crate::core::iter::Sum<&'a $V<T $(, $N)?>> for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Num {
+            fn sum<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |a, b| a + *b)
+            }
+        }
+
+        impl<T $(, const $N: usize)?> $crate::core::iter::Product for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Num {
+            fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |a, b| a * b)
+            }
+        }
+
+        impl<'a, T $(, const $N: usize)?> $crate::core::iter::Product<&'a $V<T $(, $N)?>> for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Num {
+            fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |a, b| a * *b)
+            }
+        }
+
+        // Indexing and iteration traits
+
+        impl<T: $crate::VecItem $(, const $N: usize)?> $crate::core::ops::Index<usize> for $V<T $(, $N)?> {
+            type Output = T;
+            fn index(&self, index: usize) -> &T { &self.as_slice()[index] }
+        }
+
+        impl<T: $crate::VecItem $(, const $N: usize)?> $crate::core::ops::IndexMut<usize> for $V<T $(, $N)?> {
+            fn index_mut(&mut self, index: usize) -> &mut T { &mut self.as_mut_slice()[index] }
+        }
+
+        impl<T: $crate::VecItem $(, const $N: usize)?> IntoIterator for $V<T $(, $N)?> {
+            type Item = T;
+            type IntoIter = $crate::core::array::IntoIter<T, $n>;
+            fn into_iter(self) -> $crate::core::array::IntoIter<T, $n> { IntoIterator::into_iter(self.elements()) }
+        }
+
+        impl<'a, T: $crate::VecItem $(, const $N: usize)?> IntoIterator for &'a $V<T $(, $N)?> {
+            type Item = &'a T;
+            type IntoIter = $crate::core::slice::Iter<'a, T>;
+            fn into_iter(self) -> $crate::core::slice::Iter<'a, T> { self.iter() }
+        }
+
+        impl<'a, T: $crate::VecItem $(, const $N: usize)?> IntoIterator for &'a mut $V<T $(, $N)?> {
+            type Item = &'a mut T;
+            type IntoIter = $crate::core::slice::IterMut<'a, T>;
+            fn into_iter(self) -> $crate::core::slice::IterMut<'a, T> { self.iter_mut() }
+        }
+
+        /// Collects exactly as many items as the vector has components into a vector
+        ///
+        /// *Panics if the iterator does not produce exactly that many items*
+        impl<T: $crate::VecItem $(, const $N: usize)?> $crate::core::iter::FromIterator<T> for $V<T $(, $N)?> {
+            fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
+                let mut iter = iter.into_iter();
+                let data = $crate::core::array::from_fn(|_| {
+                    iter.next().expect("iterator produced too few items to fill vector")
+                });
+                assert!(iter.next().is_none(), "iterator produced too many items to fill vector");
+                Self::from_array(data)
+            }
+        }
+    );
+}
+
+/// Implements the vector math traits shared by all vector types
+macro_rules! impl_vec_math {
+    (($V:ident $([$N:ident])?), $n:tt) => (
+        // VecNum traits
+
+        impl<T $(, const $N: usize)?> $crate::math::VecNum for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Num {
+            fn sum(&self) -> Self::Item {
+                self.fold(T::zero(), |a, b| a + b)
+            }
+
+            fn product(&self) -> Self::Item {
+                self.fold(T::one(), |a, b| a * b)
+            }
+        }
+
+        // VecInt traits
+
+        impl<T $(, const $N: usize)?> $crate::math::VecInt for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Integer {
+            fn div_euc(&self, other: Self) -> Self {
+                self.zip_map(other, |a, b| a.div_floor(&b))
+            }
+        }
+
+        // VecUnsigned traits
+
+        impl<T $(, const $N: usize)?> $crate::math::VecUnsigned for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Unsigned {}
+
+        // VecSigned traits
+
+        impl<T $(, const $N: usize)?> $crate::math::VecSigned for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Signed {
+            fn snake_length(&self) -> Self::Item {
+                $crate::math::VecNum::sum(&self.map(|e| e.abs()))
+            }
+        }
+
+        // VecFloat traits
+
+        impl<T $(, const $N: usize)?> $crate::math::VecFloat for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Float {
+            fn length(&self) -> Self::Item {
+                $crate::math::VecNum::sum(&(*self * *self)).sqrt()
+            }
+
+            fn norm(&self) -> Self {
+                let len = self.length();
+                self.map(|e| e / len)
+            }
+
+            fn floor(&self) -> Self {
+                self.map(|e| e.floor())
+            }
+
+            fn ceil(&self) -> Self {
+                self.map(|e| e.ceil())
+            }
+
+            fn round(&self) -> Self {
+                self.map(|e| e.round())
+            }
+        }
+
+        // VecInterp traits
+
+        impl<T $(, const $N: usize)?> $crate::math::VecInterp for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::num::Float {
+            fn lerp(&self, other: Self, t: T) -> Self {
+                *self + (other - *self) * t
+            }
+
+            fn inverse_lerp(&self, a: Self, b: Self) -> Self {
+                (*self - a) / (b - a)
+            }
+
+            fn remap(&self, from_min: Self, from_max: Self, to_min: Self, to_max: Self) -> Self {
+                to_min.mix(to_max, self.inverse_lerp(from_min, from_max))
+            }
+
+            fn step(&self, edge: Self) -> Self {
+                self.zip_map(edge, |e, edge| if e < edge { T::zero() } else { T::one() })
+            }
+
+            fn smoothstep(&self, edge0: Self, edge1: Self) -> Self {
+                let three = T::one() + T::one() + T::one();
+                self.inverse_lerp(edge0, edge1).saturate().map(|t| t * t * (three - (t + t)))
+            }
+
+            fn smootherstep(&self, edge0: Self, edge1: Self) -> Self {
+                let (six, ten, fifteen) = (T::from(6).unwrap(), T::from(10).unwrap(), T::from(15).unwrap());
+                self.inverse_lerp(edge0, edge1).saturate().map(|t| t * t * t * (t * (t * six - fifteen) + ten))
+            }
+
+            fn mix(&self, other: Self, t: Self) -> Self {
+                *self + (other - *self) * t
+            }
+
+            fn fract(&self) -> Self {
+                *self - $crate::math::VecFloat::floor(self)
+            }
+
+            fn clamp_components(&self, min: Self, max: Self) -> Self {
+                self.max_components(min).min_components(max)
+            }
+
+            fn saturate(&self) -> Self {
+                self.map(|e| e.max(T::zero()).min(T::one()))
+            }
+
+            fn min_components(&self, other: Self) -> Self {
+                self.zip_map(other, |a, b| a.min(b))
+            }
+
+            fn max_components(&self, other: Self) -> Self {
+                self.zip_map(other, |a, b| a.max(b))
+            }
+
+            fn abs(&self) -> Self {
+                self.map(|e| e.abs())
+            }
+
+            fn signum(&self) -> Self {
+                self.map(|e| e.signum())
+            }
+
+            fn mul_add(&self, a: Self, b: Self) -> Self {
+                let (v, a, b) = (self.elements(), a.elements(), b.elements());
+                $V::from_array($crate::core::array::from_fn(|i| v[i].mul_add(a[i], b[i])))
+            }
+
+            fn recip(&self) -> Self {
+                self.map(|e| e.recip())
+            }
+        }
+    );
+}
+
+/// Implements the `ZERO` and `ONE` associated constants, along with the given `UNIT_*` constants, upon a vector type
+/// for primitive component types
+macro_rules! impl_vec_consts {
+    ($vec:tt, $n:tt $units:tt; $($prim:ty),*) => (
+        impl_vec_consts!($vec, $n $units; $($prim),*; 0, 1);
+    );
+    ($vec:tt, $n:tt $units:tt; $($prim:ty),*; $zero:expr, $one:expr) => ($(
+        impl_vec_consts!(@impl $vec, $n $units; $prim, $zero, $one);
+    )*);
+    (@impl ($V:ident $([$N:ident])?), $n:tt [$($unit:ident $axis:ident $index:tt),*]; $prim:ty, $zero:expr, $one:expr) => (
+        impl<$(const $N: usize)?> $V<$prim $(, $N)?> {
+            /// A vector with every component set to zero
+            pub const ZERO: Self = Self::splat($zero);
+            /// A vector with every component set to one
+            pub const ONE: Self = Self::splat($one);
+            $(
+                #[doc = concat!("A vector of unit length along the ", stringify!($axis), " axis")]
+                pub const $unit: Self = {
+                    let mut data = [$zero; $n];
+                    data[$index] = $one;
+                    Self::from_array(data)
+                };
+            )*
+        }
+    );
+}
+
+/// Implements a family of explicit-overflow arithmetic methods upon a vector type, with both vector and primitive forms
+macro_rules! impl_vec_overflow_op {
+    (checked ($V:ident $([$N:ident])?), $n:tt; $trait:ident, $fn:ident, $fn_scalar:ident, $desc:expr) => (
+        impl<T: $crate::VecItem + $crate::num::$trait $(, const $N: usize)?> $V<T $(, $N)?> {
+            #[doc = concat!("Performs a checked ", $desc, " with another vector, returning `None` if any component overflows")]
+            pub fn $fn(&self, other: Self) -> Option<Self> {
+                let mut data = self.elements();
+                for (out, b) in data.iter_mut().zip(other.iter()) {
+                    *out = out.$fn(b)?;
+                }
+                Some($V::from_array(data))
+            }
+
+            #[doc = concat!("Performs a checked ", $desc, " with a primitive, returning `None` if any component overflows")]
+            pub fn $fn_scalar(&self, other: T) -> Option<Self> {
+                self.$fn(Self::splat(other))
+            }
+        }
+    );
+    (wrapping ($V:ident $([$N:ident])?), $n:tt; $trait:ident, $fn:ident, $fn_scalar:ident, $desc:expr) => (
+        impl<T: $crate::VecItem + $crate::num::traits::$trait $(, const $N: usize)?> $V<T $(, $N)?> {
+            #[doc = concat!("Performs a wrapping ", $desc, " with another vector")]
+            pub fn $fn(&self, other: Self) -> Self {
+                self.zip_map(other, |a, b| a.$fn(&b))
+            }
+
+            #[doc = concat!("Performs a wrapping ", $desc, " with a primitive")]
+            pub fn $fn_scalar(&self, other: T) -> Self {
+                self.map(|a| a.$fn(&other))
+            }
+        }
+    );
+    (saturating ($V:ident $([$N:ident])?), $n:tt; $trait:ident, $fn:ident, $fn_scalar:ident, $desc:expr) => (
+        impl<T: $crate::VecItem + $crate::num::traits::$trait $(, const $N: usize)?> $V<T $(, $N)?> {
+            #[doc = concat!("Performs a saturating ", $desc, " with another vector, clamping overflowing components to the bounds of the type")]
+            pub fn $fn(&self, other: Self) -> Self {
+                self.zip_map(other, |a, b| a.$fn(&b))
+            }
+
+            #[doc = concat!("Performs a saturating ", $desc, " with a primitive, clamping overflowing components to the bounds of the type")]
+            pub fn $fn_scalar(&self, other: T) -> Self {
+                self.map(|a| a.$fn(&other))
+            }
+        }
+    );
+    (overflowing ($V:ident $([$N:ident])?), $n:tt; $trait:ident, $fn:ident, $fn_scalar:ident, $desc:expr) => (
+        impl<T: $crate::VecItem + $crate::num::traits::ops::overflowing::$trait $(, const $N: usize)?> $V<T $(, $N)?> {
+            #[doc = concat!("Performs a wrapping ", $desc, " with another vector, along with a mask of the components that overflowed")]
+            pub fn $fn(&self, other: Self) -> (Self, $V<bool $(, $N)?>) {
+                let (mut data, mut mask) = (self.elements(), [false; $n]);
+                for ((out, overflow), b) in data.iter_mut().zip(mask.iter_mut()).zip(other.iter()) {
+                    let (e, o) = out.$fn(b);
+                    *out = e;
+                    *overflow = o;
+                }
+                ($V::from_array(data), $V::from_array(mask))
+            }
+
+            #[doc = concat!("Performs a wrapping ", $desc, " with a primitive, along with a mask of the components that overflowed")]
+            pub fn $fn_scalar(&self, other: T) -> (Self, $V<bool $(, $N)?>) {
+                self.$fn(Self::splat(other))
+            }
+        }
+    );
+}
+
+/// Implements a binary operator upon a vector type component-wise, along with its primitive, assign and primitive
+/// assign forms
+macro_rules! impl_vec_op {
+    (($V:ident $([$N:ident])?); $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $sym:tt) => (
+        impl<T $(, const $N: usize)?> $crate::core::ops::$op for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self, other: Self) -> $V<T::Output $(, $N)?> {
+                self.zip_map(other, |a, b| a $sym b)
+            }
+        }
+
+        impl<T $(, const $N: usize)?> $crate::core::ops::$op<T> for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self, other: T) -> $V<T::Output $(, $N)?> {
+                self.map(|a| a $sym other)
+            }
+        }
+
+        impl<T $(, const $N: usize)?> $crate::core::ops::$op_assign for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op<Output=T> {
+            fn $fn_assign(&mut self, other: Self) {
+                *self = *self $sym other;
+            }
+        }
+
+        impl<T $(, const $N: usize)?> $crate::core::ops::$op_assign<T> for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op<Output=T> {
+            fn $fn_assign(&mut self, other: T) {
+                *self = *self $sym other;
+            }
+        }
+
+        impl<'a, T $(, const $N: usize)?> $crate::core::ops::$op<&'a $V<T $(, $N)?>> for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self, other: &'a $V<T $(, $N)?>) -> $V<T::Output $(, $N)?> {
+                self $sym *other
+            }
+        }
+
+        impl<'a, T $(, const $N: usize)?> $crate::core::ops::$op<$V<T $(, $N)?>> for &'a $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self, other: $V<T $(, $N)?>) -> $V<T::Output $(, $N)?> {
+                *self $sym other
+            }
+        }
+
+        impl<'a, 'b, T $(, const $N: usize)?> $crate::core::ops::$op<&'b $V<T $(, $N)?>> for &'a $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self, other: &'b $V<T $(, $N)?>) -> $V<T::Output $(, $N)?> {
+                *self $sym *other
+            }
+        }
+
+        impl<'a, T $(, const $N: usize)?> $crate::core::ops::$op<T> for &'a $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self, other: T) -> $V<T::Output $(, $N)?> {
+                *self $sym other
+            }
+        }
+
+        impl<'a, T $(, const $N: usize)?> $crate::core::ops::$op_assign<&'a $V<T $(, $N)?>> for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op<Output=T> {
+            fn $fn_assign(&mut self, other: &'a $V<T $(, $N)?>) {
+                *self = *self $sym *other;
+            }
+        }
+    );
+}
+
+/// Implements a unary operator upon a vector type component-wise, along with its reference form
+macro_rules! impl_vec_unary_op {
+    (($V:ident $([$N:ident])?); $op:ident, $fn:ident, $sym:tt) => (
+        impl<T $(, const $N: usize)?> $crate::core::ops::$op for $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self) -> $V<T::Output $(, $N)?> {
+                self.map(|e| $sym e)
+            }
+        }
+
+        impl<'a, T $(, const $N: usize)?> $crate::core::ops::$op for &'a $V<T $(, $N)?>
+            where T: $crate::VecItem + $crate::core::ops::$op, T::Output: $crate::VecItem {
+            type Output = $V<T::Output $(, $N)?>;
+            fn $fn(self) -> $V<T::Output $(, $N)?> {
+                $sym *self
+            }
+        }
+    );
+}
+
+/// Implements binary operators with a primitive on the left-hand side and a vector type on the right-hand side
+macro_rules! impl_vec_scalar_lhs_op {
+    ($vec:tt; $($prim:ty),*) => ($(
+        impl_vec_scalar_lhs_op!(@impl $vec; $prim; Add, add, +);
+        impl_vec_scalar_lhs_op!(@impl $vec; $prim; Sub, sub, -);
+        impl_vec_scalar_lhs_op!(@impl $vec; $prim; Mul, mul, *);
+        impl_vec_scalar_lhs_op!(@impl $vec; $prim; Div, div, /);
+        impl_vec_scalar_lhs_op!(@impl $vec; $prim; Rem, rem, %);
+    )*);
+    (@impl ($V:ident $([$N:ident])?); $prim:ty; $op:ident, $fn:ident, $sym:tt) => (
+        impl<$(const $N: usize)?> $crate::core::ops::$op<$V<$prim $(, $N)?>> for $prim {
+            type Output = $V<$prim $(, $N)?>;
+            fn $fn(self, other: $V<$prim $(, $N)?>) -> $V<$prim $(, $N)?> {
+                other.map(|e| self $sym e)
+            }
+        }
+
+        impl<'a, $(const $N: usize)?> $crate::core::ops::$op<&'a $V<$prim $(, $N)?>> for $prim {
+            type Output = $V<$prim $(, $N)?>;
+            fn $fn(self, other: &'a $V<$prim $(, $N)?>) -> $V<$prim $(, $N)?> {
+                self $sym *other
+            }
+        }
+    );
+}
+
+/// Implements `TryFrom` between vectors of every pair of distinct primitive numeric component types
+macro_rules! impl_vec_try_from {
+    ($vec:tt) => (
+        impl_vec_try_from!(@pairs $vec []; u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64);
+    );
+    (@pairs $vec:tt [$($done:ident)*];) => ();
+    (@pairs $vec:tt [$($done:ident)*]; $cur:ident $($rest:ident)*) => (
+        $(impl_vec_try_from!(@impl $vec; $cur => $done);)*
+        $(impl_vec_try_from!(@impl $vec; $cur => $rest);)*
+        impl_vec_try_from!(@pairs $vec [$($done)* $cur]; $($rest)*);
+    );
+    (@impl ($V:ident $([$N:ident])?); $from:ident => $to:ident) => (
+        impl<$(const $N: usize)?> $crate::core::convert::TryFrom<$V<$from $(, $N)?>> for $V<$to $(, $N)?> {
+            type Error = $crate::cast::CastError;
+            fn try_from(v: $V<$from $(, $N)?>) -> Result<Self, $crate::cast::CastError> {
+                v.try_cast()
+            }
+        }
+    );
+}
+
+/// Implements the `UNIT_*` associated constants upon `VecN` of one to four components for primitive component types
+macro_rules! impl_vecn_unit_consts {
+    ($($prim:ty),*) => (
+        impl_vecn_unit_consts!($($prim),*; 0, 1);
+    );
+    ($($prim:ty),*; $zero:expr, $one:expr) => ($(
+        impl $crate::vecn::VecN<$prim, 1> {
+            /// A vector of unit length along the x axis
+            pub const UNIT_X: Self = Self::from_array([$one]);
+        }
+
+        impl $crate::vecn::VecN<$prim, 2> {
+            /// A vector of unit length along the x axis
+            pub const UNIT_X: Self = Self::from_array([$one, $zero]);
+            /// A vector of unit length along the y axis
+            pub const UNIT_Y: Self = Self::from_array([$zero, $one]);
+        }
+
+        impl $crate::vecn::VecN<$prim, 3> {
+            /// A vector of unit length along the x axis
+            pub const UNIT_X: Self = Self::from_array([$one, $zero, $zero]);
+            /// A vector of unit length along the y axis
+            pub const UNIT_Y: Self = Self::from_array([$zero, $one, $zero]);
+            /// A vector of unit length along the z axis
+            pub const UNIT_Z: Self = Self::from_array([$zero, $zero, $one]);
+        }
+
+        impl $crate::vecn::VecN<$prim, 4> {
+            /// A vector of unit length along the x axis
+            pub const UNIT_X: Self = Self::from_array([$one, $zero, $zero, $zero]);
+            /// A vector of unit length along the y axis
+            pub const UNIT_Y: Self = Self::from_array([$zero, $one, $zero, $zero]);
+            /// A vector of unit length along the z axis
+            pub const UNIT_Z: Self = Self::from_array([$zero, $zero, $one, $zero]);
+            /// A vector of unit length along the w axis
+            pub const UNIT_W: Self = Self::from_array([$zero, $zero, $zero, $one]);
+        }
+    )*);
+}
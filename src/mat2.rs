@@ -0,0 +1,146 @@
+//! Functionality pertaining to `Mat2`
+
+use core::ops::{Mul, MulAssign};
+use core::fmt;
+
+#[allow(unused_imports)]
+use num::{Num, Float};
+
+use super::VecItem;
+use super::vec2::Vec2;
+
+/// A 2x2 matrix, stored as 2 column vectors
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Mat2<T: VecItem> {
+    pub cols: [Vec2<T>; 2],
+}
+
+/// A 2x2 matrix, stored as 2 column vectors
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Mat2<T: VecItem> {
+    pub cols: [Vec2<T>; 2],
+}
+
+impl<T: VecItem> Mat2<T> {
+    /// Creates a new Mat2 from its column vectors
+    pub fn new(x: Vec2<T>, y: Vec2<T>) -> Self { Self { cols: [x, y] } }
+
+    /// Creates a new Mat2 from its row vectors
+    pub fn from_rows(x: Vec2<T>, y: Vec2<T>) -> Self { Self::new(x, y).transpose() }
+
+    /// Returns the column vectors of the matrix
+    pub fn cols(&self) -> [Vec2<T>; 2] { self.cols }
+
+    /// Returns the row vectors of the matrix
+    pub fn rows(&self) -> [Vec2<T>; 2] { self.transpose().cols }
+
+    /// Returns the elements of the matrix as an array of columns
+    pub fn elements(&self) -> [[T; 2]; 2] { [self.cols[0].elements(), self.cols[1].elements()] }
+
+    /// Apply an operation to all elements of this matrix, returning the result
+    pub fn map<U: VecItem, F: Fn(T) -> U>(&self, f: F) -> Mat2<U> {
+        Mat2 {
+            cols: [self.cols[0].map(&f), self.cols[1].map(&f)],
+        }
+    }
+
+    /// Returns the transpose of the matrix *(i.e: the matrix with its rows and columns swapped)*
+    pub fn transpose(&self) -> Self {
+        let [x, y] = self.cols;
+        Self::new(
+            Vec2::new(x.x, y.x),
+            Vec2::new(x.y, y.y),
+        )
+    }
+}
+
+impl<T: VecItem + Num> Mat2<T> {
+    /// Creates a new Mat2 with all elements set to zero
+    pub fn zero() -> Self { Self::default().map(|_| T::zero()) }
+
+    /// Creates a new identity Mat2
+    pub fn identity() -> Self { Self::from_diagonal(Vec2::new(T::one(), T::one())) }
+
+    /// Creates a new Mat2 with the given diagonal and all other elements set to zero
+    pub fn from_diagonal(d: Vec2<T>) -> Self {
+        Self::new(
+            Vec2::new(d.x, T::zero()),
+            Vec2::new(T::zero(), d.y),
+        )
+    }
+
+    /// Calculates the determinant of the matrix
+    pub fn determinant(&self) -> T {
+        let [x, y] = self.cols;
+        x.x * y.y - y.x * x.y
+    }
+}
+
+impl<T: VecItem + Float> Mat2<T> {
+    /// Calculates the inverse of the matrix, returning `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+
+        let [x, y] = self.cols;
+        Some(Self::new(
+            Vec2::new(y.y, -x.y) / det,
+            Vec2::new(-y.x, x.x) / det,
+        ))
+    }
+}
+
+// Debug and Display traits
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Mat2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{:?}, {:?}]", self.cols[0], self.cols[1])
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Mat2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.cols[0], self.cols[1])
+    }
+}
+
+// From traits
+
+impl<T: VecItem> From<[Vec2<T>; 2]> for Mat2<T> {
+    fn from(cols: [Vec2<T>; 2]) -> Self { Self { cols } }
+}
+
+impl<T: VecItem> From<[[T; 2]; 2]> for Mat2<T> {
+    fn from(arr: [[T; 2]; 2]) -> Self { Self::new(Vec2::from(arr[0]), Vec2::from(arr[1])) }
+}
+
+// Op traits
+
+impl<T> Mul for Mat2<T> where T: VecItem + Num {
+    type Output = Mat2<T>;
+    fn mul(self, other: Self) -> Mat2<T> {
+        Mat2::new(
+            self * other.cols[0],
+            self * other.cols[1],
+        )
+    }
+}
+
+impl<T> Mul<Vec2<T>> for Mat2<T> where T: VecItem + Num {
+    type Output = Vec2<T>;
+    fn mul(self, other: Vec2<T>) -> Vec2<T> {
+        self.cols[0] * other.x + self.cols[1] * other.y
+    }
+}
+
+// Assign operators
+
+impl<T> MulAssign for Mat2<T> where T: VecItem + Num {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other
+    }
+}
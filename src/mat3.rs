@@ -0,0 +1,158 @@
+//! Functionality pertaining to `Mat3`
+
+use core::ops::{Mul, MulAssign};
+use core::fmt;
+
+#[allow(unused_imports)]
+use num::{Num, Float};
+
+use super::VecItem;
+use super::math::VecCross;
+use super::vec3::Vec3;
+
+/// A 3x3 matrix, stored as 3 column vectors
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Mat3<T: VecItem> {
+    pub cols: [Vec3<T>; 3],
+}
+
+/// A 3x3 matrix, stored as 3 column vectors
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Mat3<T: VecItem> {
+    pub cols: [Vec3<T>; 3],
+}
+
+impl<T: VecItem> Mat3<T> {
+    /// Creates a new Mat3 from its column vectors
+    pub fn new(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Self { Self { cols: [x, y, z] } }
+
+    /// Creates a new Mat3 from its row vectors
+    pub fn from_rows(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Self { Self::new(x, y, z).transpose() }
+
+    /// Returns the column vectors of the matrix
+    pub fn cols(&self) -> [Vec3<T>; 3] { self.cols }
+
+    /// Returns the row vectors of the matrix
+    pub fn rows(&self) -> [Vec3<T>; 3] { self.transpose().cols }
+
+    /// Returns the elements of the matrix as an array of columns
+    pub fn elements(&self) -> [[T; 3]; 3] {
+        [self.cols[0].elements(), self.cols[1].elements(), self.cols[2].elements()]
+    }
+
+    /// Apply an operation to all elements of this matrix, returning the result
+    pub fn map<U: VecItem, F: Fn(T) -> U>(&self, f: F) -> Mat3<U> {
+        Mat3 {
+            cols: [self.cols[0].map(&f), self.cols[1].map(&f), self.cols[2].map(&f)],
+        }
+    }
+
+    /// Returns the transpose of the matrix *(i.e: the matrix with its rows and columns swapped)*
+    pub fn transpose(&self) -> Self {
+        let [x, y, z] = self.cols;
+        Self::new(
+            Vec3::new(x.x, y.x, z.x),
+            Vec3::new(x.y, y.y, z.y),
+            Vec3::new(x.z, y.z, z.z),
+        )
+    }
+}
+
+impl<T: VecItem + Num> Mat3<T> {
+    /// Creates a new Mat3 with all elements set to zero
+    pub fn zero() -> Self { Self::default().map(|_| T::zero()) }
+
+    /// Creates a new identity Mat3
+    pub fn identity() -> Self { Self::from_diagonal(Vec3::new(T::one(), T::one(), T::one())) }
+
+    /// Creates a new Mat3 with the given diagonal and all other elements set to zero
+    pub fn from_diagonal(d: Vec3<T>) -> Self {
+        Self::new(
+            Vec3::new(d.x, T::zero(), T::zero()),
+            Vec3::new(T::zero(), d.y, T::zero()),
+            Vec3::new(T::zero(), T::zero(), d.z),
+        )
+    }
+
+    /// Calculates the determinant of the matrix
+    pub fn determinant(&self) -> T {
+        let [x, y, z] = self.cols;
+        x.x * (y.y * z.z - z.y * y.z)
+            - y.x * (x.y * z.z - z.y * x.z)
+            + z.x * (x.y * y.z - y.y * x.z)
+    }
+}
+
+impl<T: VecItem + Float> Mat3<T> {
+    /// Calculates the inverse of the matrix, returning `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+
+        // The rows of the inverse are the cross products of pairs of columns
+        let [x, y, z] = self.cols;
+        Some(Self::from_rows(
+            y.cross(z) / det,
+            z.cross(x) / det,
+            x.cross(y) / det,
+        ))
+    }
+}
+
+// Debug and Display traits
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Mat3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{:?}, {:?}, {:?}]", self.cols[0], self.cols[1], self.cols[2])
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Mat3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.cols[0], self.cols[1], self.cols[2])
+    }
+}
+
+// From traits
+
+impl<T: VecItem> From<[Vec3<T>; 3]> for Mat3<T> {
+    fn from(cols: [Vec3<T>; 3]) -> Self { Self { cols } }
+}
+
+impl<T: VecItem> From<[[T; 3]; 3]> for Mat3<T> {
+    fn from(arr: [[T; 3]; 3]) -> Self {
+        Self::new(Vec3::from(arr[0]), Vec3::from(arr[1]), Vec3::from(arr[2]))
+    }
+}
+
+// Op traits
+
+impl<T> Mul for Mat3<T> where T: VecItem + Num {
+    type Output = Mat3<T>;
+    fn mul(self, other: Self) -> Mat3<T> {
+        Mat3::new(
+            self * other.cols[0],
+            self * other.cols[1],
+            self * other.cols[2],
+        )
+    }
+}
+
+impl<T> Mul<Vec3<T>> for Mat3<T> where T: VecItem + Num {
+    type Output = Vec3<T>;
+    fn mul(self, other: Vec3<T>) -> Vec3<T> {
+        self.cols[0] * other.x + self.cols[1] * other.y + self.cols[2] * other.z
+    }
+}
+
+// Assign operators
+
+impl<T> MulAssign for Mat3<T> where T: VecItem + Num {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other
+    }
+}
@@ -0,0 +1,214 @@
+//! Functionality pertaining to `Mat4`
+
+use core::ops::{Mul, MulAssign};
+use core::fmt;
+
+#[allow(unused_imports)]
+use num::{Num, Float};
+
+use super::VecItem;
+use super::vec4::Vec4;
+
+/// A 4x4 matrix, stored as 4 column vectors
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Mat4<T: VecItem> {
+    pub cols: [Vec4<T>; 4],
+}
+
+/// A 4x4 matrix, stored as 4 column vectors
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Mat4<T: VecItem> {
+    pub cols: [Vec4<T>; 4],
+}
+
+impl<T: VecItem> Mat4<T> {
+    /// Creates a new Mat4 from its column vectors
+    pub fn new(x: Vec4<T>, y: Vec4<T>, z: Vec4<T>, w: Vec4<T>) -> Self { Self { cols: [x, y, z, w] } }
+
+    /// Creates a new Mat4 from its row vectors
+    pub fn from_rows(x: Vec4<T>, y: Vec4<T>, z: Vec4<T>, w: Vec4<T>) -> Self { Self::new(x, y, z, w).transpose() }
+
+    /// Returns the column vectors of the matrix
+    pub fn cols(&self) -> [Vec4<T>; 4] { self.cols }
+
+    /// Returns the row vectors of the matrix
+    pub fn rows(&self) -> [Vec4<T>; 4] { self.transpose().cols }
+
+    /// Returns the elements of the matrix as an array of columns
+    pub fn elements(&self) -> [[T; 4]; 4] {
+        [self.cols[0].elements(), self.cols[1].elements(), self.cols[2].elements(), self.cols[3].elements()]
+    }
+
+    /// Apply an operation to all elements of this matrix, returning the result
+    pub fn map<U: VecItem, F: Fn(T) -> U>(&self, f: F) -> Mat4<U> {
+        Mat4 {
+            cols: [self.cols[0].map(&f), self.cols[1].map(&f), self.cols[2].map(&f), self.cols[3].map(&f)],
+        }
+    }
+
+    /// Returns the transpose of the matrix *(i.e: the matrix with its rows and columns swapped)*
+    pub fn transpose(&self) -> Self {
+        let [x, y, z, w] = self.cols;
+        Self::new(
+            Vec4::new(x.x, y.x, z.x, w.x),
+            Vec4::new(x.y, y.y, z.y, w.y),
+            Vec4::new(x.z, y.z, z.z, w.z),
+            Vec4::new(x.w, y.w, z.w, w.w),
+        )
+    }
+}
+
+/// The 2x2 sub-determinants of the upper and lower halves of a 4x4 matrix, as used by Laplace
+/// expansion along the first two rows
+struct SubDeterminants<T> {
+    s: [T; 6],
+    c: [T; 6],
+}
+
+impl<T: VecItem + Num> SubDeterminants<T> {
+    fn of(a: &[[T; 4]; 4]) -> Self {
+        Self {
+            s: [
+                a[0][0] * a[1][1] - a[1][0] * a[0][1],
+                a[0][0] * a[1][2] - a[1][0] * a[0][2],
+                a[0][0] * a[1][3] - a[1][0] * a[0][3],
+                a[0][1] * a[1][2] - a[1][1] * a[0][2],
+                a[0][1] * a[1][3] - a[1][1] * a[0][3],
+                a[0][2] * a[1][3] - a[1][2] * a[0][3],
+            ],
+            c: [
+                a[2][0] * a[3][1] - a[3][0] * a[2][1],
+                a[2][0] * a[3][2] - a[3][0] * a[2][2],
+                a[2][0] * a[3][3] - a[3][0] * a[2][3],
+                a[2][1] * a[3][2] - a[3][1] * a[2][2],
+                a[2][1] * a[3][3] - a[3][1] * a[2][3],
+                a[2][2] * a[3][3] - a[3][2] * a[2][3],
+            ],
+        }
+    }
+
+    fn determinant(&self) -> T {
+        let (s, c) = (&self.s, &self.c);
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+}
+
+impl<T: VecItem + Num> Mat4<T> {
+    /// Creates a new Mat4 with all elements set to zero
+    pub fn zero() -> Self { Self::default().map(|_| T::zero()) }
+
+    /// Creates a new identity Mat4
+    pub fn identity() -> Self { Self::from_diagonal(Vec4::new(T::one(), T::one(), T::one(), T::one())) }
+
+    /// Creates a new Mat4 with the given diagonal and all other elements set to zero
+    pub fn from_diagonal(d: Vec4<T>) -> Self {
+        Self::new(
+            Vec4::new(d.x, T::zero(), T::zero(), T::zero()),
+            Vec4::new(T::zero(), d.y, T::zero(), T::zero()),
+            Vec4::new(T::zero(), T::zero(), d.z, T::zero()),
+            Vec4::new(T::zero(), T::zero(), T::zero(), d.w),
+        )
+    }
+
+    /// Calculates the determinant of the matrix
+    pub fn determinant(&self) -> T {
+        SubDeterminants::of(&self.transpose().elements()).determinant()
+    }
+}
+
+impl<T: VecItem + Float> Mat4<T> {
+    /// Calculates the inverse of the matrix, returning `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let a = self.transpose().elements();
+        let sub = SubDeterminants::of(&a);
+        let det = sub.determinant();
+        if det == T::zero() {
+            return None;
+        }
+
+        let (s, c) = (&sub.s, &sub.c);
+        Some(Self::from_rows(
+            Vec4::new(
+                a[1][1] * c[5] - a[1][2] * c[4] + a[1][3] * c[3],
+                -a[0][1] * c[5] + a[0][2] * c[4] - a[0][3] * c[3],
+                a[3][1] * s[5] - a[3][2] * s[4] + a[3][3] * s[3],
+                -a[2][1] * s[5] + a[2][2] * s[4] - a[2][3] * s[3],
+            ) / det,
+            Vec4::new(
+                -a[1][0] * c[5] + a[1][2] * c[2] - a[1][3] * c[1],
+                a[0][0] * c[5] - a[0][2] * c[2] + a[0][3] * c[1],
+                -a[3][0] * s[5] + a[3][2] * s[2] - a[3][3] * s[1],
+                a[2][0] * s[5] - a[2][2] * s[2] + a[2][3] * s[1],
+            ) / det,
+            Vec4::new(
+                a[1][0] * c[4] - a[1][1] * c[2] + a[1][3] * c[0],
+                -a[0][0] * c[4] + a[0][1] * c[2] - a[0][3] * c[0],
+                a[3][0] * s[4] - a[3][1] * s[2] + a[3][3] * s[0],
+                -a[2][0] * s[4] + a[2][1] * s[2] - a[2][3] * s[0],
+            ) / det,
+            Vec4::new(
+                -a[1][0] * c[3] + a[1][1] * c[1] - a[1][2] * c[0],
+                a[0][0] * c[3] - a[0][1] * c[1] + a[0][2] * c[0],
+                -a[3][0] * s[3] + a[3][1] * s[1] - a[3][2] * s[0],
+                a[2][0] * s[3] - a[2][1] * s[1] + a[2][2] * s[0],
+            ) / det,
+        ))
+    }
+}
+
+// Debug and Display traits
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Mat4<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{:?}, {:?}, {:?}, {:?}]", self.cols[0], self.cols[1], self.cols[2], self.cols[3])
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Mat4<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.cols[0], self.cols[1], self.cols[2], self.cols[3])
+    }
+}
+
+// From traits
+
+impl<T: VecItem> From<[Vec4<T>; 4]> for Mat4<T> {
+    fn from(cols: [Vec4<T>; 4]) -> Self { Self { cols } }
+}
+
+impl<T: VecItem> From<[[T; 4]; 4]> for Mat4<T> {
+    fn from(arr: [[T; 4]; 4]) -> Self {
+        Self::new(Vec4::from(arr[0]), Vec4::from(arr[1]), Vec4::from(arr[2]), Vec4::from(arr[3]))
+    }
+}
+
+// Op traits
+
+impl<T> Mul for Mat4<T> where T: VecItem + Num {
+    type Output = Mat4<T>;
+    fn mul(self, other: Self) -> Mat4<T> {
+        Mat4::new(
+            self * other.cols[0],
+            self * other.cols[1],
+            self * other.cols[2],
+            self * other.cols[3],
+        )
+    }
+}
+
+impl<T> Mul<Vec4<T>> for Mat4<T> where T: VecItem + Num {
+    type Output = Vec4<T>;
+    fn mul(self, other: Vec4<T>) -> Vec4<T> {
+        self.cols[0] * other.x + self.cols[1] * other.y + self.cols[2] * other.z + self.cols[3] * other.w
+    }
+}
+
+// Assign operators
+
+impl<T> MulAssign for Mat4<T> where T: VecItem + Num {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other
+    }
+}
@@ -1,8 +1,20 @@
 //! Mathematic functions and traits
 
 pub use num::{Num, Integer, Unsigned, Signed, Float};
+use num::{Zero, One, PrimInt, CheckedDiv};
+use num::traits::CheckedRem;
+use core::iter::FromIterator;
+use core::ops::{Sub, Mul};
 use super::{Vector, VecItem};
 
+pub mod stats;
+
+/// A trait for vectors whose components may be mapped to another type, producing a vector of the same dimension
+pub trait VecMap<U: VecItem>: Vector {
+    /// The vector produced by mapping each component to a `U` *(e.g: the `Vec3<bool>` mask of a `Vec3<f32>`)*
+    type Output: Vector<Item=U> + FromIterator<U>;
+}
+
 /// A trait for vectors containing numerical types
 pub trait VecNum: Vector where Self::Item: VecItem + Num {
     /// Calculates the sum of all components of the vector
@@ -19,25 +31,172 @@ pub trait VecNum: Vector where Self::Item: VecItem + Num {
 pub trait VecDot: Vector where Self::Item: VecItem + Num {
     /// calculates the dot product of the two vectors
     fn dot(&self, other: Self) -> Self::Item;
+
+    /// Calculates the squared magnitude of the vector
+    fn length_squared(&self) -> Self::Item where Self: Sized + Copy {
+        self.dot(*self)
+    }
+
+    /// Calculates the squared distance between the two vectors
+    fn distance_squared(&self, other: Self) -> Self::Item where Self: Sized + Copy + Sub<Output=Self> {
+        (*self - other).length_squared()
+    }
+
+    /// Reflects the vector about a surface with the given normal
+    ///
+    /// *The normal is expected to be normalized*
+    fn reflect(&self, normal: Self) -> Self where Self: Sized + Copy + Sub<Output=Self> + Mul<Self::Item, Output=Self> {
+        let d = self.dot(normal);
+        *self - normal * (d + d)
+    }
+
+    /// Calculates the projection of the vector onto `other`
+    fn project_onto(&self, other: Self) -> Self where Self: Sized + Copy + Mul<Self::Item, Output=Self> {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Calculates the component of the vector that is perpendicular to `other`
+    fn reject_from(&self, other: Self) -> Self
+        where Self: Sized + Copy + Sub<Output=Self> + Mul<Self::Item, Output=Self>
+    {
+        *self - self.project_onto(other)
+    }
 }
 
 /// Default implement the dot product for all numeric vectors
-impl<V> VecDot for V where V: Sized + Copy + VecNum + ::core::ops::Mul<Output=V>, V::Item: VecItem + Num {
+impl<V> VecDot for V where V: Sized + Copy + VecNum + Mul<Output=V>, V::Item: VecItem + Num {
     fn dot(&self, other: Self) -> Self::Item {
         (*self * other).sum()
     }
 }
 
+/// Trait for vectors that support a cross-product
+pub trait VecCross: Vector where Self::Item: VecItem + Num {
+    /// The result of the cross product *(a vector for 3-dimensional vectors, a scalar for 2-dimensional vectors)*
+    type Output;
+
+    /// Calculates the cross product of the two vectors
+    ///
+    /// *For 2-dimensional vectors, this is the 'perp-dot' product (i.e: the z component of the 3-dimensional cross
+    /// product)*
+    fn cross(&self, other: Self) -> Self::Output;
+
+    /// Calculates the signed angle from this 2-dimensional vector to `other`, in radians
+    ///
+    /// *Counter-clockwise angles are positive and clockwise angles are negative*
+    fn signed_angle(&self, other: Self) -> Self::Item
+        where Self: Sized + Copy + VecDot + VecCross<Output=<Self as Vector>::Item>, Self::Item: Float
+    {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Calculates the signed angle from this 3-dimensional vector to `other` about `axis`, in radians
+    ///
+    /// *Angles that are counter-clockwise when viewed from the tip of the axis are positive*
+    fn signed_angle_around(&self, other: Self, axis: Self) -> Self::Item
+        where Self: Sized + Copy + VecDot + VecFloat + VecCross<Output=Self>, Self::Item: Float
+    {
+        self.cross(other).dot(axis.norm()).atan2(self.dot(other))
+    }
+}
+
 
 
 /// A trait for vectors containing integer types
 pub trait VecInt: Vector where Self::Item: VecItem + Integer {
     /// Performs a Eucledian division (i.e: rounds towards negative infinity) operation upon the vector
     fn div_euc(&self, other: Self) -> Self;
+
+    /// Calculates the remainder of a Eucledian division (i.e: the remainder that corresponds to `.div_euc()`)
+    fn rem_euc(&self, other: Self) -> Self where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]> {
+        zip_map(self, &other, |a, b| a.mod_floor(&b))
+    }
+
+    /// Performs a Eucledian division, returning both the quotient and the remainder
+    fn div_rem_euc(&self, other: Self) -> (Self, Self)
+        where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]>
+    {
+        (self.div_euc(other), self.rem_euc(other))
+    }
+
+    /// Performs a division that rounds towards positive infinity
+    fn div_ceil(&self, other: Self) -> Self where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]> {
+        zip_map(self, &other, |a, b| Integer::div_ceil(&a, &b))
+    }
+
+    /// Calculates the greatest common divisor of each pair of components of the two vectors
+    fn gcd(&self, other: Self) -> Self where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]> {
+        zip_map(self, &other, |a, b| a.gcd(&b))
+    }
+
+    /// Calculates the lowest common multiple of each pair of components of the two vectors
+    fn lcm(&self, other: Self) -> Self where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]> {
+        zip_map(self, &other, |a, b| a.lcm(&b))
+    }
+
+    /// Returns a mask of the components of the vector that are multiples of the corresponding components of `other`
+    fn is_multiple_of(&self, other: Self) -> <Self as VecMap<bool>>::Output
+        where Self: Sized + VecMap<bool> + AsRef<[Self::Item]>
+    {
+        self.as_ref().iter().zip(other.as_ref()).map(|(a, b)| Integer::is_multiple_of(a, b)).collect()
+    }
+
+    /// Raises each element of the vector to the power `exp`
+    fn pow(&self, exp: u32) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, |e| ::num::pow(e, exp as usize))
+    }
+
+    /// Performs a division, returning `None` if any component of `other` is zero or the division overflows
+    fn checked_div(&self, other: Self) -> Option<Self>
+        where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]>, Self::Item: CheckedDiv
+    {
+        try_zip_map(self, &other, |a, b| a.checked_div(&b))
+    }
+
+    /// Calculates the remainder of a division, returning `None` if any component of `other` is zero or the division
+    /// overflows
+    fn checked_rem(&self, other: Self) -> Option<Self>
+        where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]>, Self::Item: CheckedRem
+    {
+        try_zip_map(self, &other, |a, b| a.checked_rem(&b))
+    }
 }
 
 /// A trait for vectors containing unsigned numerical types
-pub trait VecUnsigned: Vector where Self::Item: VecItem + Unsigned {}
+pub trait VecUnsigned: Vector where Self::Item: VecItem + Unsigned {
+    /// Returns a mask of the components of the vector that are powers of two
+    fn is_power_of_two(&self) -> <Self as VecMap<bool>>::Output
+        where Self: Sized + VecMap<bool> + AsRef<[Self::Item]>, Self::Item: PrimInt
+    {
+        map_to(self, |e| e.count_ones() == 1)
+    }
+
+    /// Calculates the smallest power of two greater than or equal to each element of the vector
+    ///
+    /// *Elements greater than the largest power of two that the type can represent overflow*
+    fn next_power_of_two(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]>, Self::Item: PrimInt {
+        let bits = Self::Item::zero().count_zeros();
+        map(self, |e| if e <= Self::Item::one() {
+            Self::Item::one()
+        } else {
+            Self::Item::one() << (bits - (e - Self::Item::one()).leading_zeros()) as usize
+        })
+    }
+
+    /// Counts the number of leading zero bits in each element of the vector
+    fn leading_zeros(&self) -> <Self as VecMap<u32>>::Output
+        where Self: Sized + VecMap<u32> + AsRef<[Self::Item]>, Self::Item: PrimInt
+    {
+        map_to(self, |e| e.leading_zeros())
+    }
+
+    /// Counts the number of trailing zero bits in each element of the vector
+    fn trailing_zeros(&self) -> <Self as VecMap<u32>>::Output
+        where Self: Sized + VecMap<u32> + AsRef<[Self::Item]>, Self::Item: PrimInt
+    {
+        map_to(self, |e| e.trailing_zeros())
+    }
+}
 
 /// A trait for vectors containing signed numerical types
 pub trait VecSigned: Vector where Self::Item: VecItem + Signed {
@@ -48,6 +207,9 @@ pub trait VecSigned: Vector where Self::Item: VecItem + Signed {
 }
 
 /// A trait for vectors containing floating point numerical types
+///
+/// *The per-component functions are provided methods, rather than plain `.map()` calls, so that vector types with a
+/// SIMD representation may override them*
 pub trait VecFloat: Vector where Self::Item: VecItem + Float {
     /// Calculates the magnitude of the vector
     fn length(&self) -> Self::Item;
@@ -62,4 +224,235 @@ pub trait VecFloat: Vector where Self::Item: VecItem + Float {
 
     /// Rounds each element of the vector to the nearest whole number
     fn round(&self) -> Self;
+
+    /// Rounds each element of the vector towards zero
+    fn trunc(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::trunc)
+    }
+
+    /// Calculates the square root of each element of the vector
+    fn sqrt(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::sqrt)
+    }
+
+    /// Raises each element of the vector to the floating point power `n`
+    fn powf(&self, n: Self::Item) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, |e| e.powf(n))
+    }
+
+    /// Raises each element of the vector to the integer power `n`
+    fn powi(&self, n: i32) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, |e| e.powi(n))
+    }
+
+    /// Calculates `e^x` for each element of the vector
+    fn exp(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::exp)
+    }
+
+    /// Calculates `2^x` for each element of the vector
+    fn exp2(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::exp2)
+    }
+
+    /// Calculates the natural logarithm of each element of the vector
+    fn ln(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::ln)
+    }
+
+    /// Calculates the base 2 logarithm of each element of the vector
+    fn log2(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::log2)
+    }
+
+    /// Calculates the base 10 logarithm of each element of the vector
+    fn log10(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::log10)
+    }
+
+    /// Calculates the sine of each element of the vector, in radians
+    fn sin(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::sin)
+    }
+
+    /// Calculates the cosine of each element of the vector, in radians
+    fn cos(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::cos)
+    }
+
+    /// Calculates the tangent of each element of the vector, in radians
+    fn tan(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::tan)
+    }
+
+    /// Calculates the arcsine of each element of the vector, in radians
+    fn asin(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::asin)
+    }
+
+    /// Calculates the arccosine of each element of the vector, in radians
+    fn acos(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::acos)
+    }
+
+    /// Calculates the arctangent of each element of the vector, in radians
+    fn atan(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::atan)
+    }
+
+    /// Calculates the hyperbolic sine of each element of the vector
+    fn sinh(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::sinh)
+    }
+
+    /// Calculates the hyperbolic cosine of each element of the vector
+    fn cosh(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::cosh)
+    }
+
+    /// Calculates the hyperbolic tangent of each element of the vector
+    fn tanh(&self) -> Self where Self: Sized + Copy + AsMut<[Self::Item]> {
+        map(self, Float::tanh)
+    }
+
+    /// Calculates the four quadrant arctangent of each element of the vector *(as `y`)* and the corresponding element
+    /// of `other` *(as `x`)*, in radians
+    fn atan2(&self, other: Self) -> Self where Self: Sized + Copy + AsRef<[Self::Item]> + AsMut<[Self::Item]> {
+        zip_map(self, &other, Float::atan2)
+    }
+
+    /// Returns a mask of the elements of the vector that are NaN
+    fn is_nan(&self) -> <Self as VecMap<bool>>::Output where Self: Sized + VecMap<bool> + AsRef<[Self::Item]> {
+        map_to(self, Float::is_nan)
+    }
+
+    /// Returns a mask of the elements of the vector that are neither infinite nor NaN
+    fn is_finite(&self) -> <Self as VecMap<bool>>::Output where Self: Sized + VecMap<bool> + AsRef<[Self::Item]> {
+        map_to(self, Float::is_finite)
+    }
+
+    /// Returns a mask of the elements of the vector that are positive or negative infinity
+    fn is_infinite(&self) -> <Self as VecMap<bool>>::Output where Self: Sized + VecMap<bool> + AsRef<[Self::Item]> {
+        map_to(self, Float::is_infinite)
+    }
+
+    /// Calculates the distance between the two vectors
+    fn distance(&self, other: Self) -> Self::Item where Self: Sized + Copy + Sub<Output=Self> {
+        (*self - other).length()
+    }
+
+    /// Refracts the vector through a surface with the given normal and ratio of refractive indices, returning `None`
+    /// upon total internal reflection
+    ///
+    /// *Both the vector and the normal are expected to be normalized*
+    fn refract(&self, normal: Self, eta: Self::Item) -> Option<Self>
+        where Self: Sized + Copy + VecDot + Sub<Output=Self> + Mul<Self::Item, Output=Self>
+    {
+        let d = self.dot(normal);
+        let k = Self::Item::one() - eta * eta * (Self::Item::one() - d * d);
+        if k < Self::Item::zero() {
+            None
+        } else {
+            Some(*self * eta - normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// Calculates the unsigned angle between the two vectors, in radians
+    fn angle_between(&self, other: Self) -> Self::Item where Self: Sized + Copy + VecDot {
+        let cos = self.dot(other) / (self.length() * other.length());
+        cos.max(-Self::Item::one()).min(Self::Item::one()).acos()
+    }
+}
+
+/// A trait for floating point vectors that support interpolation and other common shader-style functions
+///
+/// *The component-wise minimum, maximum and clamp are named `min_components`, `max_components` and
+/// `clamp_components` so that they cannot be confused with the methods of `Ord`. For integer vectors, `.min()`,
+/// `.max()` and `.clamp()` resolve to `Ord` and compare the vectors lexicographically*
+pub trait VecInterp: Vector where Self::Item: VecItem + Float {
+    /// Linearly interpolates between this vector and `other` by the factor `t`
+    fn lerp(&self, other: Self, t: Self::Item) -> Self;
+
+    /// Calculates the factor by which each component of the vector lies between `a` and `b` *(i.e: the inverse of
+    /// `.lerp()`)*
+    fn inverse_lerp(&self, a: Self, b: Self) -> Self;
+
+    /// Maps each component of the vector from the range `from_min..from_max` to the range `to_min..to_max`
+    fn remap(&self, from_min: Self, from_max: Self, to_min: Self, to_max: Self) -> Self;
+
+    /// Returns 0 for each component of the vector less than `edge`, and 1 otherwise
+    fn step(&self, edge: Self) -> Self;
+
+    /// Performs a smooth Hermite interpolation between 0 and 1 for each component of the vector between `edge0` and
+    /// `edge1`
+    fn smoothstep(&self, edge0: Self, edge1: Self) -> Self;
+
+    /// Performs Ken Perlin's improved smooth interpolation between 0 and 1 for each component of the vector between
+    /// `edge0` and `edge1`
+    fn smootherstep(&self, edge0: Self, edge1: Self) -> Self;
+
+    /// Linearly interpolates between this vector and `other` using a separate factor for each component
+    fn mix(&self, other: Self, t: Self) -> Self;
+
+    /// Calculates the fractional part of each element of the vector *(i.e: `x - x.floor()`)*
+    fn fract(&self) -> Self;
+
+    /// Restricts each element of the vector to the range `min..=max`
+    fn clamp_components(&self, min: Self, max: Self) -> Self;
+
+    /// Restricts each element of the vector to the range `0..=1`
+    fn saturate(&self) -> Self;
+
+    /// Calculates the component-wise minimum of the two vectors
+    fn min_components(&self, other: Self) -> Self;
+
+    /// Calculates the component-wise maximum of the two vectors
+    fn max_components(&self, other: Self) -> Self;
+
+    /// Calculates the absolute value of each element of the vector
+    fn abs(&self) -> Self;
+
+    /// Calculates the sign of each element of the vector
+    fn signum(&self) -> Self;
+
+    /// Calculates `(self * a) + b` with only one rounding error for each element of the vector
+    fn mul_add(&self, a: Self, b: Self) -> Self;
+
+    /// Calculates the reciprocal *(i.e: `1 / x`)* of each element of the vector
+    fn recip(&self) -> Self;
+}
+
+// Per-component helpers for the provided methods of the vector traits, which a vector type may override with its own
+// implementations
+
+fn map<V, F>(v: &V, f: F) -> V where V: Vector + Copy + AsMut<[V::Item]>, F: Fn(V::Item) -> V::Item {
+    let mut out = *v;
+    for e in out.as_mut() {
+        *e = f(*e);
+    }
+    out
+}
+
+fn zip_map<V, F>(a: &V, b: &V, f: F) -> V
+    where V: Vector + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, F: Fn(V::Item, V::Item) -> V::Item
+{
+    let mut out = *a;
+    for (e, &b) in out.as_mut().iter_mut().zip(b.as_ref()) {
+        *e = f(*e, b);
+    }
+    out
+}
+
+fn map_to<V, U, F>(v: &V, f: F) -> V::Output where V: VecMap<U> + AsRef<[V::Item]>, U: VecItem, F: Fn(V::Item) -> U {
+    v.as_ref().iter().map(|&e| f(e)).collect()
+}
+
+fn try_zip_map<V, F>(a: &V, b: &V, f: F) -> Option<V>
+    where V: Vector + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, F: Fn(V::Item, V::Item) -> Option<V::Item>
+{
+    let mut out = *a;
+    for (e, &b) in out.as_mut().iter_mut().zip(b.as_ref()) {
+        *e = f(*e, b)?;
+    }
+    Some(out)
 }
@@ -0,0 +1,141 @@
+//! Statistical functions over collections of vectors
+//!
+//! *Each function accepts any iterable collection of vectors or references to vectors, including slices, and returns
+//! `None` when given no vectors*
+
+use core::borrow::Borrow;
+use core::ops::{Add, Sub, Mul, Div, AddAssign};
+
+use num::{Float, Zero, One};
+
+use super::super::{Vector, VecItem};
+use super::super::vecn::VecN;
+use super::super::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+use super::super::mat3::Mat3;
+
+/// A vector, or a reference to a vector, that may be passed to the statistical functions
+///
+/// *This allows each function to accept both collections of vectors and collections of references to vectors while
+/// still inferring the type of vector produced*
+pub trait Sample {
+    /// The type of vector
+    type Vector: Vector + Copy;
+
+    /// Returns the sampled vector
+    fn vector(&self) -> Self::Vector;
+}
+
+impl<S: Sample> Sample for &S {
+    type Vector = S::Vector;
+    fn vector(&self) -> S::Vector { (*self).vector() }
+}
+
+macro_rules! impl_sample {
+    ($V:ident $([$N:ident])?) => (
+        impl<T: VecItem $(, const $N: usize)?> Sample for $V<T $(, $N)?> {
+            type Vector = Self;
+            fn vector(&self) -> Self { *self }
+        }
+    );
+}
+
+impl_sample!(VecN[N]);
+impl_sample!(Vec1);
+impl_sample!(Vec2);
+impl_sample!(Vec3);
+impl_sample!(Vec4);
+
+/// Calculates the mean position of a collection of points
+pub fn centroid<V, I>(points: I) -> Option<V>
+    where V: Vector + Copy + Default + Add<Output=V> + Mul<V::Item, Output=V> + Div<V::Item, Output=V>,
+          V::Item: Float,
+          I: IntoIterator,
+          I::Item: Sample<Vector=V>
+{
+    weighted_centroid(points.into_iter().map(|p| (p, V::Item::one())))
+}
+
+/// Calculates the mean position of a collection of points, with each point contributing according to its weight
+///
+/// *Returns `None` if the weights sum to zero*
+pub fn weighted_centroid<V, I, P>(points: I) -> Option<V>
+    where V: Vector + Copy + Default + Add<Output=V> + Mul<V::Item, Output=V> + Div<V::Item, Output=V>,
+          V::Item: Float,
+          I: IntoIterator<Item=(P, V::Item)>,
+          P: Sample<Vector=V>
+{
+    let (sum, total) = points.into_iter().fold((V::default(), V::Item::zero()), |(sum, total), (p, w)| {
+        (sum + p.vector() * w, total + w)
+    });
+    if total == V::Item::zero() { None } else { Some(sum / total) }
+}
+
+/// Calculates the component-wise minimum of a collection of vectors *(i.e: the minimum corner of their bounding box)*
+pub fn component_min<V, I>(vecs: I) -> Option<V>
+    where V: Vector + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, V::Item: PartialOrd, I: IntoIterator, I::Item: Sample<Vector=V>
+{
+    vecs.into_iter().map(|v| v.vector()).reduce(|mut a, b| {
+        for (a, b) in a.as_mut().iter_mut().zip(b.as_ref().iter()) {
+            if *b < *a {
+                *a = *b;
+            }
+        }
+        a
+    })
+}
+
+/// Calculates the component-wise maximum of a collection of vectors *(i.e: the maximum corner of their bounding box)*
+pub fn component_max<V, I>(vecs: I) -> Option<V>
+    where V: Vector + Copy + AsRef<[V::Item]> + AsMut<[V::Item]>, V::Item: PartialOrd, I: IntoIterator, I::Item: Sample<Vector=V>
+{
+    vecs.into_iter().map(|v| v.vector()).reduce(|mut a, b| {
+        for (a, b) in a.as_mut().iter_mut().zip(b.as_ref().iter()) {
+            if *b > *a {
+                *a = *b;
+            }
+        }
+        a
+    })
+}
+
+/// Calculates the population variance of each component of a collection of vectors
+pub fn variance<V, I>(vecs: I) -> Option<V>
+    where V: Vector + Copy + Default + Sub<Output=V> + Mul<Output=V> + Div<V::Item, Output=V> + AddAssign,
+          V::Item: Float,
+          I: IntoIterator,
+          I::Item: Sample<Vector=V>
+{
+    // Welford's algorithm, which remains numerically stable in a single pass
+    let (mut count, mut mean, mut m2) = (V::Item::zero(), V::default(), V::default());
+    for v in vecs {
+        let v = v.vector();
+        count = count + V::Item::one();
+        let delta = v - mean;
+        mean += delta / count;
+        m2 += delta * (v - mean);
+    }
+    if count == V::Item::zero() { None } else { Some(m2 / count) }
+}
+
+/// Calculates the population covariance matrix of a collection of 3-dimensional vectors
+pub fn covariance<T, I>(vecs: I) -> Option<Mat3<T>>
+    where T: VecItem + Float, I: IntoIterator, I::Item: Borrow<Vec3<T>>
+{
+    // Welford's algorithm, generalised to the outer product of each deviation from the mean
+    let (mut count, mut mean, mut m2) = (T::zero(), Vec3::<T>::default(), [Vec3::<T>::default(); 3]);
+    for v in vecs {
+        let v = *v.borrow();
+        count = count + T::one();
+        let delta = v - mean;
+        mean += delta / count;
+        let after = v - mean;
+        for (col, e) in m2.iter_mut().zip(after.iter()) {
+            *col += delta * *e;
+        }
+    }
+    if count == T::zero() {
+        None
+    } else {
+        Some(Mat3::new(m2[0] / count, m2[1] / count, m2[2] / count))
+    }
+}
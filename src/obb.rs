@@ -0,0 +1,263 @@
+//! Functionality pertaining to `Obb3`
+
+use core::array;
+use core::borrow::Borrow;
+use core::fmt;
+
+use num::Float;
+
+use super::VecItem;
+use super::math::{VecNum, VecDot, VecCross, VecFloat};
+use super::math::stats;
+use super::vec3::Vec3;
+use super::mat3::Mat3;
+use super::aabb::Aabb3;
+use super::geometry::Triangle3;
+use super::geometry::closest;
+
+/// A 3-dimensional oriented bounding box, spanning the region within `half_extents` of its center along each of its
+/// axes
+///
+/// *The columns of `orientation` are the axes of the box, and are expected to be normalized and mutually
+/// perpendicular*
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Obb3<T: VecItem> {
+    pub center: Vec3<T>,
+    pub half_extents: Vec3<T>,
+    pub orientation: Mat3<T>,
+}
+
+/// A 3-dimensional oriented bounding box, spanning the region within `half_extents` of its center along each of its
+/// axes
+///
+/// *The columns of `orientation` are the axes of the box, and are expected to be normalized and mutually
+/// perpendicular*
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Obb3<T: VecItem> {
+    pub center: Vec3<T>,
+    pub half_extents: Vec3<T>,
+    pub orientation: Mat3<T>,
+}
+
+/// The result of a successful overlap test
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Penetration<T: VecItem> {
+    /// The distance by which the shapes overlap along `axis`
+    pub depth: T,
+    /// The normalized axis along which the second shape must be moved by `depth` to separate it from the first
+    pub axis: Vec3<T>,
+}
+
+impl<T: VecItem + Float> Obb3<T> {
+    /// Creates a new Obb3 from its center, half-extents and orientation
+    pub fn new(center: Vec3<T>, half_extents: Vec3<T>, orientation: Mat3<T>) -> Self {
+        Self { center, half_extents, orientation }
+    }
+
+    /// Creates a new Obb3 from its center, half-extents and three axes
+    pub fn from_axes(center: Vec3<T>, half_extents: Vec3<T>, axes: [Vec3<T>; 3]) -> Self {
+        Self::new(center, half_extents, Mat3::new(axes[0], axes[1], axes[2]))
+    }
+
+    /// Creates a new Obb3 that covers the same region as an axis-aligned bounding box
+    pub fn from_aabb(aabb: Aabb3<T>) -> Self {
+        Self::new(aabb.center(), aabb.extent(), Mat3::identity())
+    }
+
+    /// Creates an Obb3 that contains all of the given points, with axes aligned to their principal components,
+    /// returning `None` if there are no points
+    pub fn from_points<I>(points: I) -> Option<Self> where I: IntoIterator + Clone, I::Item: Borrow<Vec3<T>> {
+        let axes = principal_axes(stats::covariance(points.clone())?);
+
+        // Find the extent of the points along each axis
+        let mut min = Vec3::splat(T::infinity());
+        let mut max = Vec3::splat(T::neg_infinity());
+        for p in points {
+            let local = Vec3::new(axes[0].dot(*p.borrow()), axes[1].dot(*p.borrow()), axes[2].dot(*p.borrow()));
+            min = min.zip_map(local, T::min);
+            max = max.zip_map(local, T::max);
+        }
+
+        let mid = (min + max) / (T::one() + T::one());
+        let center = axes[0] * mid.x + axes[1] * mid.y + axes[2] * mid.z;
+        Some(Self::from_axes(center, (max - min) / (T::one() + T::one()), axes))
+    }
+
+    /// Returns the axes of the box
+    pub fn axes(&self) -> [Vec3<T>; 3] {
+        self.orientation.cols
+    }
+
+    /// Returns the 8 corners of the box
+    pub fn corners(&self) -> [Vec3<T>; 8] {
+        let [x, y, z] = self.axes();
+        let (x, y, z) = (x * self.half_extents.x, y * self.half_extents.y, z * self.half_extents.z);
+        let c = self.center;
+        [
+            c - x - y - z, c + x - y - z, c - x + y - z, c + x + y - z,
+            c - x - y + z, c + x - y + z, c - x + y + z, c + x + y + z,
+        ]
+    }
+
+    /// Calculates the volume of the box
+    pub fn volume(&self) -> T {
+        (self.half_extents * (T::one() + T::one())).product()
+    }
+
+    /// Returns the smallest axis-aligned bounding box that contains the box
+    pub fn aabb(&self) -> Aabb3<T> {
+        let extent = self.axes().iter().zip(self.half_extents.iter())
+            .fold(Vec3::splat(T::zero()), |extent, (axis, &e)| extent + axis.map(|c| c.abs()) * e);
+        Aabb3::from_center_extent(self.center, extent)
+    }
+
+    /// Returns the point within the box that is closest to the point `p`
+    pub fn closest_point(&self, p: Vec3<T>) -> Vec3<T> {
+        closest::point_obb(p, self.center, self.axes(), self.half_extents).b
+    }
+
+    /// Returns `true` if the point `p` lies within the box or upon its boundary
+    pub fn contains_point(&self, p: Vec3<T>) -> bool {
+        let d = p - self.center;
+        self.axes().iter().zip(self.half_extents.iter()).all(|(axis, &e)| d.dot(*axis).abs() <= e)
+    }
+
+    // The interval covered by the projection of the box onto `axis`
+    fn project(&self, axis: Vec3<T>) -> (T, T) {
+        let center = self.center.dot(axis);
+        let radius = self.axes().iter().zip(self.half_extents.iter())
+            .fold(T::zero(), |r, (a, &e)| r + a.dot(axis).abs() * e);
+        (center - radius, center + radius)
+    }
+
+    /// Tests whether the box overlaps another, using the separating axis theorem
+    ///
+    /// *Returns the minimum penetration of the boxes, or `None` if they are separated*
+    pub fn overlap_obb(&self, other: Obb3<T>) -> Option<Penetration<T>> {
+        let (a, b) = (self.axes(), other.axes());
+        let mut axes = [Vec3::splat(T::zero()); 15];
+        axes[..3].copy_from_slice(&a);
+        axes[3..6].copy_from_slice(&b);
+        for i in 0..3 {
+            for j in 0..3 {
+                axes[6 + i * 3 + j] = a[i].cross(b[j]);
+            }
+        }
+        separate(&axes, |axis| (self.project(axis), other.project(axis)))
+    }
+
+    /// Tests whether the box overlaps an axis-aligned bounding box, using the separating axis theorem
+    ///
+    /// *Returns the minimum penetration of the boxes, or `None` if they are separated*
+    pub fn overlap_aabb(&self, aabb: Aabb3<T>) -> Option<Penetration<T>> {
+        self.overlap_obb(Obb3::from_aabb(aabb))
+    }
+
+    /// Tests whether the box overlaps a triangle, using the separating axis theorem
+    ///
+    /// *Returns the minimum penetration of the box and the triangle, or `None` if they are separated*
+    pub fn overlap_triangle(&self, tri: Triangle3<T>) -> Option<Penetration<T>> {
+        let a = self.axes();
+        // The edges are normalized so that the lengths of the candidate axes do not depend on the size of the triangle
+        let edges = [(tri.b - tri.a).norm(), (tri.c - tri.b).norm(), (tri.a - tri.c).norm()];
+        let mut axes = [Vec3::splat(T::zero()); 13];
+        axes[..3].copy_from_slice(&a);
+        axes[3] = edges[0].cross(edges[1]);
+        for i in 0..3 {
+            for j in 0..3 {
+                axes[4 + i * 3 + j] = a[i].cross(edges[j]);
+            }
+        }
+        separate(&axes, |axis| {
+            let (da, db, dc) = (tri.a.dot(axis), tri.b.dot(axis), tri.c.dot(axis));
+            (self.project(axis), (da.min(db).min(dc), da.max(db).max(dc)))
+        })
+    }
+}
+
+// Finds the axis of least overlap between two shapes, given a function that projects each shape onto an axis,
+// returning `None` if any axis separates them
+//
+// Axes that are close to zero, such as the cross products of parallel edges, are skipped
+fn separate<T, F>(axes: &[Vec3<T>], project: F) -> Option<Penetration<T>>
+    where T: VecItem + Float, F: Fn(Vec3<T>) -> ((T, T), (T, T))
+{
+    let mut best: Option<Penetration<T>> = None;
+    for axis in axes.iter().filter(|axis| axis.length_squared() > T::epsilon()) {
+        let axis = axis.norm();
+        let ((amin, amax), (bmin, bmax)) = project(axis);
+
+        // Moving the second shape forwards or backwards along the axis by these distances separates the shapes
+        let (forwards, backwards) = (amax - bmin, bmax - amin);
+        if forwards < T::zero() || backwards < T::zero() {
+            return None;
+        }
+
+        let pen = if forwards < backwards {
+            Penetration { depth: forwards, axis }
+        } else {
+            Penetration { depth: backwards, axis: -axis }
+        };
+        if best.is_none_or(|best| pen.depth < best.depth) {
+            best = Some(pen);
+        }
+    }
+    best
+}
+
+// Finds the eigenvectors of a symmetric matrix, such as a covariance matrix, using the Jacobi eigenvalue algorithm
+//
+// The resulting axes are normalized, mutually perpendicular and form a right-handed basis
+fn principal_axes<T: VecItem + Float>(m: Mat3<T>) -> [Vec3<T>; 3] {
+    let mut a = m.elements();
+    let mut v = Mat3::<T>::identity().elements();
+    for _ in 0..32 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        if off <= T::min_positive_value() {
+            break;
+        }
+
+        for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+            if a[p][q] == T::zero() {
+                continue;
+            }
+
+            // Rotate the matrix in the `pq` plane such that the element at `pq` becomes zero
+            let theta = (a[q][q] - a[p][p]) / (a[p][q] + a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+            let c = T::one() / (t * t + T::one()).sqrt();
+            let s = t * c;
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+            let (rp, rq) = (a[p], a[q]);
+            a[p] = array::from_fn(|k| c * rp[k] - s * rq[k]);
+            a[q] = array::from_fn(|k| s * rp[k] + c * rq[k]);
+        }
+    }
+
+    let x = Vec3::new(v[0][0], v[1][0], v[2][0]).norm();
+    let y = Vec3::new(v[0][1], v[1][1], v[2][1]).norm();
+    [x, y, x.cross(y)]
+}
+
+// Debug and Display traits
+
+impl<T: VecItem + fmt::Debug> fmt::Debug for Obb3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "(center: {:?}, half_extents: {:?}, orientation: {:?})",
+            self.center, self.half_extents, self.orientation,
+        )
+    }
+}
+
+impl<T: VecItem + fmt::Display> fmt::Display for Obb3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.center, self.half_extents, self.orientation)
+    }
+}
@@ -0,0 +1,79 @@
+//! Functionality pertaining to `TotalOrd`, a floating point wrapper with a total ordering
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::fmt;
+
+use super::VecItem;
+
+/// A floating point number with total equality, ordering and hashing, allowing vectors of it to be sorted and used as
+/// map keys
+///
+/// Values are ordered according to the IEEE 754 `totalOrder` predicate *(i.e: `-NaN < -inf < ... < -0.0 < +0.0 < ... <
+/// +inf < +NaN`)*, except that every NaN is canonicalised to a single positive NaN that compares equal to itself and
+/// greater than all other values.
+#[derive(Copy, Clone, Default)]
+pub struct TotalOrd<F>(pub F);
+
+/// A 32-bit floating point number with a total ordering
+pub type OrdF32 = TotalOrd<f32>;
+
+/// A 64-bit floating point number with a total ordering
+pub type OrdF64 = TotalOrd<f64>;
+
+macro_rules! impl_total_ord {
+    ($float:ty, $bits:ty, $ubits:ty) => (
+        impl TotalOrd<$float> {
+            // Maps the float to an integer with the same ordering, such that all NaNs map to the same integer
+            fn key(&self) -> $bits {
+                let bits = if self.0.is_nan() { <$float>::NAN.to_bits() } else { self.0.to_bits() } as $bits;
+                bits ^ ((((bits >> (<$bits>::BITS - 1)) as $ubits) >> 1) as $bits)
+            }
+        }
+
+        impl VecItem for TotalOrd<$float> {}
+
+        impl PartialEq for TotalOrd<$float> {
+            fn eq(&self, other: &Self) -> bool { self.key() == other.key() }
+        }
+
+        impl Eq for TotalOrd<$float> {}
+
+        impl PartialOrd for TotalOrd<$float> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+        }
+
+        impl Ord for TotalOrd<$float> {
+            fn cmp(&self, other: &Self) -> Ordering { self.key().cmp(&other.key()) }
+        }
+
+        impl Hash for TotalOrd<$float> {
+            fn hash<H: Hasher>(&self, state: &mut H) { self.key().hash(state) }
+        }
+
+        impl From<$float> for TotalOrd<$float> {
+            fn from(f: $float) -> Self { TotalOrd(f) }
+        }
+
+        impl From<TotalOrd<$float>> for $float {
+            fn from(f: TotalOrd<$float>) -> Self { f.0 }
+        }
+    );
+}
+
+impl_total_ord!(f32, i32, u32);
+impl_total_ord!(f64, i64, u64);
+
+// Debug and Display traits
+
+impl<F: fmt::Debug> fmt::Debug for TotalOrd<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for TotalOrd<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,239 @@
+//! Functionality pertaining to `Quat`
+
+use core::ops::{Mul, MulAssign};
+use core::fmt;
+
+use num::{Float, cast};
+
+use super::VecItem;
+use super::math::{VecDot, VecCross, VecFloat};
+use super::vec3::Vec3;
+use super::vec4::Vec4;
+use super::mat3::Mat3;
+use super::mat4::Mat4;
+
+/// A quaternion, used to represent rotations in 3-dimensional space
+///
+/// The `x`, `y` and `z` components form the vector part of the quaternion and `w` is the scalar part.
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Quat<T: VecItem + Float> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+/// A quaternion, used to represent rotations in 3-dimensional space
+///
+/// The `x`, `y` and `z` components form the vector part of the quaternion and `w` is the scalar part.
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Quat<T: VecItem + Float> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: VecItem + Float> Quat<T> {
+    /// Creates a new Quat from multiple components
+    pub fn new(x: T, y: T, z: T, w: T) -> Self { Self { x, y, z, w } }
+
+    /// Creates a new identity Quat *(i.e: a quaternion representing no rotation)*
+    pub fn identity() -> Self { Self::new(T::zero(), T::zero(), T::zero(), T::one()) }
+
+    /// Creates a new Quat representing a rotation of `angle` radians about `axis`
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        let v = axis.norm() * half.sin();
+        Self::new(v.x, v.y, v.z, half.cos())
+    }
+
+    /// Creates a new Quat representing the shortest rotation that maps the direction `from` onto the direction `to`
+    pub fn from_vectors(from: Vec3<T>, to: Vec3<T>) -> Self {
+        let (from, to) = (from.norm(), to.norm());
+        let d = from.dot(to);
+
+        // Directions that point in opposite directions have no unique rotation axis, so pick any perpendicular one
+        if d <= -T::one() + T::epsilon() {
+            let mut axis = Vec3::new(T::one(), T::zero(), T::zero()).cross(from);
+            if axis.length_squared() <= T::epsilon() {
+                axis = Vec3::new(T::zero(), T::one(), T::zero()).cross(from);
+            }
+            return Self::from_axis_angle(axis, cast(::core::f64::consts::PI).unwrap());
+        }
+
+        let c = from.cross(to);
+        Self::new(c.x, c.y, c.z, T::one() + d).norm()
+    }
+
+    /// Creates a new Quat from a pure rotation matrix
+    pub fn from_mat3(mat: Mat3<T>) -> Self {
+        let m = mat.transpose().elements();
+        let (one, two, four) = (T::one(), T::one() + T::one(), T::one() + T::one() + T::one() + T::one());
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new((m[2][1] - m[1][2]) / s, (m[0][2] - m[2][0]) / s, (m[1][0] - m[0][1]) / s, s / four)
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            Self::new(s / four, (m[0][1] + m[1][0]) / s, (m[0][2] + m[2][0]) / s, (m[2][1] - m[1][2]) / s)
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            Self::new((m[0][1] + m[1][0]) / s, s / four, (m[1][2] + m[2][1]) / s, (m[0][2] - m[2][0]) / s)
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            Self::new((m[0][2] + m[2][0]) / s, (m[1][2] + m[2][1]) / s, s / four, (m[1][0] - m[0][1]) / s)
+        }
+    }
+
+    /// Returns the elements of the quaternion as an array
+    pub fn elements(&self) -> [T; 4] { [self.x, self.y, self.z, self.w] }
+
+    /// Returns the vector part of the quaternion
+    pub fn vector(&self) -> Vec3<T> { Vec3::new(self.x, self.y, self.z) }
+
+    /// Returns the scalar part of the quaternion
+    pub fn scalar(&self) -> T { self.w }
+
+    /// Returns the quaternion as a `Vec4`, with the scalar part in the `w` component
+    pub fn to_vec4(&self) -> Vec4<T> { Vec4::new(self.x, self.y, self.z, self.w) }
+
+    /// Calculates the dot product of the two quaternions, treating them as 4-dimensional vectors
+    pub fn dot(&self, other: Self) -> T { self.to_vec4().dot(other.to_vec4()) }
+
+    /// Calculates the magnitude of the quaternion
+    pub fn length(&self) -> T { self.to_vec4().length() }
+
+    /// Calculates the normalized form of the quaternion *(i.e: a quaternion with a magnitude of 1)*
+    pub fn norm(&self) -> Self { Self::from(self.to_vec4().norm()) }
+
+    /// Returns the conjugate of the quaternion *(i.e: the quaternion with its vector part negated)*
+    pub fn conjugate(&self) -> Self { Self::new(-self.x, -self.y, -self.z, self.w) }
+
+    /// Calculates the multiplicative inverse of the quaternion
+    ///
+    /// *For normalized quaternions, this is identical to the `.conjugate()` of the quaternion*
+    pub fn inverse(&self) -> Self { Self::from(self.conjugate().to_vec4() / self.dot(*self)) }
+
+    /// Rotates a vector by the rotation that this quaternion represents
+    pub fn rotate(&self, v: Vec3<T>) -> Vec3<T> {
+        (*self * Self::new(v.x, v.y, v.z, T::zero()) * self.inverse()).vector()
+    }
+
+    /// Normalized linear interpolation between two quaternions, taking the shortest path
+    pub fn nlerp(&self, other: Self, t: T) -> Self {
+        let end = if self.dot(other) < T::zero() { other.to_vec4().map(|e| -e) } else { other.to_vec4() };
+        Self::from(self.to_vec4() * (T::one() - t) + end * t).norm()
+    }
+
+    /// Spherical linear interpolation between two quaternions, taking the shortest path
+    pub fn slerp(&self, other: Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut end = other.to_vec4();
+        if cos < T::zero() {
+            cos = -cos;
+            end = end.map(|e| -e);
+        }
+
+        // Fall back to linear interpolation when the quaternions are almost parallel to avoid dividing by zero
+        if cos > cast(0.9995).unwrap() {
+            return self.nlerp(other, t);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin;
+        let b = (t * theta).sin() / sin;
+        Self::from(self.to_vec4() * a + end * b)
+    }
+
+    /// Converts the quaternion to the equivalent rotation matrix
+    pub fn to_mat3(&self) -> Mat3<T> {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let (one, two) = (T::one(), T::one() + T::one());
+        Mat3::new(
+            Vec3::new(one - two * (y * y + z * z), two * (x * y + w * z), two * (x * z - w * y)),
+            Vec3::new(two * (x * y - w * z), one - two * (x * x + z * z), two * (y * z + w * x)),
+            Vec3::new(two * (x * z + w * y), two * (y * z - w * x), one - two * (x * x + y * y)),
+        )
+    }
+
+    /// Converts the quaternion to the equivalent homogeneous rotation matrix
+    pub fn to_mat4(&self) -> Mat4<T> {
+        let [x, y, z] = self.to_mat3().cols;
+        Mat4::new(
+            Vec4::new(x.x, x.y, x.z, T::zero()),
+            Vec4::new(y.x, y.y, y.z, T::zero()),
+            Vec4::new(z.x, z.y, z.z, T::zero()),
+            Vec4::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
+
+// Debug and Display traits
+
+impl<T: VecItem + Float + fmt::Debug> fmt::Debug for Quat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(x: {:?}, y: {:?}, z: {:?}, w: {:?})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl<T: VecItem + Float + fmt::Display> fmt::Display for Quat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+// From traits
+
+impl<T: VecItem + Float> From<Vec4<T>> for Quat<T> {
+    fn from(v: Vec4<T>) -> Self { Self::new(v.x, v.y, v.z, v.w) }
+}
+
+impl<T: VecItem + Float> From<Quat<T>> for Vec4<T> {
+    fn from(q: Quat<T>) -> Self { q.to_vec4() }
+}
+
+impl<T: VecItem + Float> From<Mat3<T>> for Quat<T> {
+    fn from(m: Mat3<T>) -> Self { Self::from_mat3(m) }
+}
+
+impl<T: VecItem + Float> From<Quat<T>> for Mat3<T> {
+    fn from(q: Quat<T>) -> Self { q.to_mat3() }
+}
+
+impl<T: VecItem + Float> From<Quat<T>> for Mat4<T> {
+    fn from(q: Quat<T>) -> Self { q.to_mat4() }
+}
+
+// Op traits
+
+impl<T: VecItem + Float> Mul for Quat<T> {
+    type Output = Quat<T>;
+    fn mul(self, other: Self) -> Quat<T> {
+        Quat::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        )
+    }
+}
+
+impl<T: VecItem + Float> Mul<Vec3<T>> for Quat<T> {
+    type Output = Vec3<T>;
+    fn mul(self, other: Vec3<T>) -> Vec3<T> {
+        self.rotate(other)
+    }
+}
+
+// Assign operators
+
+impl<T: VecItem + Float> MulAssign for Quat<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other
+    }
+}
@@ -0,0 +1,293 @@
+//! Functionality pertaining to `Ray` and `Segment`
+
+use core::fmt;
+
+use num::Float;
+
+use super::{Vector, VecItem};
+use super::math::{VecDot, VecCross, VecFloat};
+use super::vecn::VecN;
+use super::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+use super::aabb::Aabb;
+
+/// A half-infinite line, starting at an origin and extending forever in a single direction
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Ray<V> {
+    pub origin: V,
+    pub dir: V,
+}
+
+/// A half-infinite line, starting at an origin and extending forever in a single direction
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Ray<V> {
+    pub origin: V,
+    pub dir: V,
+}
+
+/// A 2-dimensional ray
+pub type Ray2<T> = Ray<Vec2<T>>;
+
+/// A 3-dimensional ray
+pub type Ray3<T> = Ray<Vec3<T>>;
+
+/// A finite line between two points
+#[cfg(feature = "serialize")]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Segment<V> {
+    pub start: V,
+    pub end: V,
+}
+
+/// A finite line between two points
+#[cfg(not(feature = "serialize"))]
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Segment<V> {
+    pub start: V,
+    pub end: V,
+}
+
+/// A 2-dimensional line segment
+pub type Segment2<T> = Segment<Vec2<T>>;
+
+/// A 3-dimensional line segment
+pub type Segment3<T> = Segment<Vec3<T>>;
+
+/// The result of a successful intersection query
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit<V: Vector> {
+    /// The distance from the start of the ray or segment to the point of intersection
+    pub distance: V::Item,
+    /// The point of intersection
+    pub point: V,
+    /// The normalized surface normal at the point of intersection
+    pub normal: V,
+}
+
+impl<V: VecFloat> Ray<V> where V::Item: Float {
+    /// Creates a new Ray from an origin and a direction, normalizing the direction
+    pub fn new(origin: V, dir: V) -> Self { Self { origin, dir: dir.norm() } }
+}
+
+impl<V> Segment<V> {
+    /// Creates a new Segment between two points
+    pub fn new(start: V, end: V) -> Self { Self { start, end } }
+}
+
+/// Implements the dimension-generic `Ray` and `Segment` methods for rays and segments of a vector type
+macro_rules! impl_ray {
+    ($V:ident $([$N:ident])?, $n:tt) => (
+        impl<T: VecItem + Float $(, const $N: usize)?> Ray<$V<T $(, $N)?>> {
+            /// Returns the point at the given distance along the ray
+            pub fn at(&self, distance: T) -> $V<T $(, $N)?> {
+                self.origin + self.dir * distance
+            }
+
+            fn hit(&self, distance: T, normal: $V<T $(, $N)?>) -> RayHit<$V<T $(, $N)?>> {
+                RayHit { distance, point: self.at(distance), normal }
+            }
+
+            /// Finds the first intersection of the ray with a sphere *(or a circle, in 2 dimensions)*
+            ///
+            /// *Rays that begin inside the sphere intersect with it where they leave it*
+            pub fn intersect_sphere(&self, center: $V<T $(, $N)?>, radius: T) -> Option<RayHit<$V<T $(, $N)?>>> {
+                let oc = self.origin - center;
+                let b = oc.dot(self.dir);
+                let disc = b * b - (oc.length_squared() - radius * radius);
+                if disc < T::zero() {
+                    return None;
+                }
+
+                let sq = disc.sqrt();
+                let distance = if -b - sq >= T::zero() { -b - sq } else { -b + sq };
+                if distance < T::zero() {
+                    return None;
+                }
+
+                let point = self.at(distance);
+                Some(self.hit(distance, (point - center).norm()))
+            }
+
+            /// Finds the intersection of the ray with the plane of points `p` that satisfy `normal.dot(p) == d` *(or a
+            /// line, in 2 dimensions)*
+            ///
+            /// *The normal of the hit faces towards the origin of the ray*
+            pub fn intersect_plane(&self, normal: $V<T $(, $N)?>, d: T) -> Option<RayHit<$V<T $(, $N)?>>> {
+                let normal = normal.norm();
+                let denom = normal.dot(self.dir);
+                if denom.abs() <= T::epsilon() {
+                    return None;
+                }
+
+                let distance = (d - normal.dot(self.origin)) / denom;
+                if distance < T::zero() {
+                    return None;
+                }
+
+                Some(self.hit(distance, if denom > T::zero() { -normal } else { normal }))
+            }
+
+            /// Finds the first intersection of the ray with an axis-aligned bounding box, using the slab method
+            ///
+            /// *Rays that begin inside the box intersect with it where they leave it*
+            pub fn intersect_aabb(&self, aabb: Aabb<$V<T $(, $N)?>>) -> Option<RayHit<$V<T $(, $N)?>>> {
+                let (origin, dir) = (self.origin.elements(), self.dir.elements());
+                let (min, max) = (aabb.min.elements(), aabb.max.elements());
+
+                // The furthest slab entry and nearest slab exit, along with the axes they occur upon
+                let (mut near, mut near_axis) = (T::neg_infinity(), 0);
+                let (mut far, mut far_axis) = (T::infinity(), 0);
+                for i in 0..$n {
+                    let (t0, t1) = ((min[i] - origin[i]) / dir[i], (max[i] - origin[i]) / dir[i]);
+                    let (t0, t1) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+                    if t0 > near {
+                        near = t0;
+                        near_axis = i;
+                    }
+                    if t1 < far {
+                        far = t1;
+                        far_axis = i;
+                    }
+                }
+
+                if near > far || far < T::zero() {
+                    return None;
+                }
+
+                let (distance, axis, sign) = if near >= T::zero() {
+                    (near, near_axis, -dir[near_axis].signum())
+                } else {
+                    (far, far_axis, dir[far_axis].signum())
+                };
+
+                let mut normal = [T::zero(); $n];
+                normal[axis] = sign;
+                Some(self.hit(distance, $V::from_array(normal)))
+            }
+        }
+
+        impl<T: VecItem + Float $(, const $N: usize)?> Segment<$V<T $(, $N)?>> {
+            /// Calculates the length of the segment
+            pub fn length(&self) -> T {
+                self.start.distance(self.end)
+            }
+
+            /// Returns the ray that starts at the start of the segment and passes through its end
+            pub fn to_ray(&self) -> Ray<$V<T $(, $N)?>> {
+                Ray::new(self.start, self.end - self.start)
+            }
+
+            /// Returns the point on the segment that is closest to the point `p`
+            pub fn closest_point(&self, p: $V<T $(, $N)?>) -> $V<T $(, $N)?> {
+                let dir = self.end - self.start;
+                let len_sq = dir.length_squared();
+                if len_sq <= T::zero() {
+                    return self.start;
+                }
+
+                let t = ((p - self.start).dot(dir) / len_sq).max(T::zero()).min(T::one());
+                self.start + dir * t
+            }
+
+            /// Calculates the distance from the segment to the point `p`
+            pub fn distance(&self, p: $V<T $(, $N)?>) -> T {
+                self.closest_point(p).distance(p)
+            }
+
+            fn within(&self, hit: Option<RayHit<$V<T $(, $N)?>>>) -> Option<RayHit<$V<T $(, $N)?>>> {
+                hit.filter(|hit| hit.distance <= self.length())
+            }
+
+            /// Finds the first intersection of the segment with a sphere *(or a circle, in 2 dimensions)*
+            pub fn intersect_sphere(&self, center: $V<T $(, $N)?>, radius: T) -> Option<RayHit<$V<T $(, $N)?>>> {
+                self.within(self.to_ray().intersect_sphere(center, radius))
+            }
+
+            /// Finds the intersection of the segment with the plane of points `p` that satisfy `normal.dot(p) == d`
+            pub fn intersect_plane(&self, normal: $V<T $(, $N)?>, d: T) -> Option<RayHit<$V<T $(, $N)?>>> {
+                self.within(self.to_ray().intersect_plane(normal, d))
+            }
+
+            /// Finds the first intersection of the segment with an axis-aligned bounding box
+            pub fn intersect_aabb(&self, aabb: Aabb<$V<T $(, $N)?>>) -> Option<RayHit<$V<T $(, $N)?>>> {
+                self.within(self.to_ray().intersect_aabb(aabb))
+            }
+        }
+    );
+}
+
+impl_ray!(VecN[N], N);
+impl_ray!(Vec1, 1);
+impl_ray!(Vec2, 2);
+impl_ray!(Vec3, 3);
+impl_ray!(Vec4, 4);
+
+impl<T: VecItem + Float> Ray<Vec3<T>> {
+    /// Finds the intersection of the ray with the triangle `abc`, using the Möller–Trumbore algorithm
+    ///
+    /// *The normal of the hit faces towards the origin of the ray*
+    pub fn intersect_triangle(&self, a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Option<RayHit<Vec3<T>>> {
+        let (e1, e2) = (b - a, c - a);
+        let p = self.dir.cross(e2);
+        let det = e1.dot(p);
+        // The ray is parallel to the triangle when `det` is negligible relative to the lengths it is the product of
+        if det * det <= T::epsilon() * T::epsilon() * e1.length_squared() * p.length_squared() {
+            return None;
+        }
+
+        let inv_det = T::one() / det;
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+
+        let q = s.cross(e1);
+        let v = self.dir.dot(q) * inv_det;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+
+        let distance = e2.dot(q) * inv_det;
+        if distance < T::zero() {
+            return None;
+        }
+
+        let normal = e1.cross(e2).norm();
+        Some(self.hit(distance, if normal.dot(self.dir) > T::zero() { -normal } else { normal }))
+    }
+}
+
+impl<T: VecItem + Float> Segment<Vec3<T>> {
+    /// Finds the intersection of the segment with the triangle `abc`
+    pub fn intersect_triangle(&self, a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Option<RayHit<Vec3<T>>> {
+        self.within(self.to_ray().intersect_triangle(a, b, c))
+    }
+}
+
+// Debug and Display traits
+
+impl<V: fmt::Debug> fmt::Debug for Ray<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(origin: {:?}, dir: {:?})", self.origin, self.dir)
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Ray<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.origin, self.dir)
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Segment<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(start: {:?}, end: {:?})", self.start, self.end)
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Segment<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.start, self.end)
+    }
+}
@@ -1,287 +1,88 @@
 //! Functionality pertaining to `Vec1`
 
-use core::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
-use core::fmt;
+use num::Num;
 
-#[allow(unused_imports)]
-use num::{Num, Integer, Unsigned, Signed, Float};
-
-#[allow(unused_imports)]
-use super::{Vector, VecItem, VecNum, VecInt, VecUnsigned, VecSigned, VecFloat};
+use super::VecItem;
+use super::vecn::VecN;
+use super::{vec2::Vec2, vec3::Vec3, vec4::Vec4};
 
+/// A 1-dimensional vector
 #[cfg(feature = "serialize")]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Vec1<T: VecItem> {
     pub x: T,
 }
 
+/// A 1-dimensional vector
 #[cfg(not(feature = "serialize"))]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+#[repr(C)]
 pub struct Vec1<T: VecItem> {
     pub x: T,
 }
 
 impl<T: VecItem> Vec1<T> {
     /// Creates a new Vec1 from a single component
-    pub fn new(x: T) -> Self { Self { x, ..Default::default() } }
+    pub const fn new(x: T) -> Self { Self { x } }
+
+    /// Creates a new Vec1 from an array of components
+    pub const fn from_array(arr: [T; 1]) -> Self {
+        let [x] = arr;
+        Self { x }
+    }
 
     /// Returns the elements of the vector as an array
     pub fn elements(&self) -> [T; 1] { [self.x] }
 
-    /// Apply an operation to all elements of this vector, returning the result
-    pub fn map<U: VecItem, F: Fn(T) -> U>(&self, f: F) -> Vec1<U> {
-        Vec1 {
-            x: f(self.x),
-        }
+    /// Returns the elements of the vector as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // Safe because `Vec1<T>` is `#[repr(C)]` and has exactly the layout of `[T; 1]`
+        unsafe { &*(self as *const Self as *const [T; 1]) }
     }
 
-    pub fn convert_to<U: VecItem + From<T>>(&self) -> Vec1<U> {
-        Vec1 {
-            x: U::from(self.x),
-        }
+    /// Returns the elements of the vector as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safe because `Vec1<T>` is `#[repr(C)]` and has exactly the layout of `[T; 1]`
+        unsafe { &mut *(self as *mut Self as *mut [T; 1]) }
     }
 }
 
-impl<T: VecItem> Vector for Vec1<T> {
-    type Item = T;
+impl<T: VecItem + Num> Vec1<T> {
+    /// Creates a new Vec1 of unit length along the x axis
+    pub fn unit_x() -> Self { Self::new(T::one()) }
 }
 
-// Debug and Display traits
-
-impl<T: VecItem + fmt::Debug> fmt::Debug for Vec1<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "(x: {:?})", self.x)
-    }
-}
-
-impl<T: VecItem + fmt::Display> fmt::Display for Vec1<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({})", self.x)
-    }
-}
+impl_vec!(Vec1, 1 [UNIT_X x 0]);
 
 // From traits
 
-impl<T: VecItem> From<T> for Vec1<T> {
-    fn from(item: T) -> Self { Self { x: item, ..Default::default() } }
-}
-
-impl<T: VecItem> From<[T; 1]> for Vec1<T> {
-    fn from(arr: [T; 1]) -> Self { Self { x: arr[0], ..Default::default() } }
-}
-
-// Op traits
-
-impl<T> Add for Vec1<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec1<T::Output>;
-    fn add(self, other: Self) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x + other.x,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Sub for Vec1<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec1<T::Output>;
-    fn sub(self, other: Self) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x - other.x,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Mul for Vec1<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec1<T::Output>;
-    fn mul(self, other: Self) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x * other.x,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<VecN<T, 1>> for Vec1<T> {
+    fn from(v: VecN<T, 1>) -> Self { Self::from_array(v.elements()) }
 }
 
-impl<T> Div for Vec1<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec1<T::Output>;
-    fn div(self, other: Self) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x / other.x,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<Vec1<T>> for VecN<T, 1> {
+    fn from(v: Vec1<T>) -> Self { Self::from_array(v.elements()) }
 }
 
-// Op primitive traits
-
-impl<T> Add<T> for Vec1<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec1<T::Output>;
-    fn add(self, other: T) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x + other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Sub<T> for Vec1<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec1<T::Output>;
-    fn sub(self, other: T) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x - other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Mul<T> for Vec1<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec1<T::Output>;
-    fn mul(self, other: T) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x * other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Div<T> for Vec1<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec1<T::Output>;
-    fn div(self, other: T) -> Vec1<T::Output> {
-        Vec1 {
-            x: self.x / other,
-            ..Default::default()
-        }
-    }
-}
-
-// Assign operators
-
-impl<T> AddAssign for Vec1<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: Self) {
-        *self = Vec1 {
-            x: self.x + other.x,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> SubAssign for Vec1<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: Self) {
-        *self = Vec1 {
-            x: self.x - other.x,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> MulAssign for Vec1<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: Self) {
-        *self = Vec1 {
-            x: self.x * other.x,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> DivAssign for Vec1<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: Self) {
-        *self = Vec1 {
-            x: self.x / other.x,
-            ..Default::default()
-        }
-    }
-}
-
-// Assign primitive operators
-
-impl<T> AddAssign<T> for Vec1<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: T) {
-        *self = Vec1 {
-            x: self.x + other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> SubAssign<T> for Vec1<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: T) {
-        *self = Vec1 {
-            x: self.x - other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> MulAssign<T> for Vec1<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: T) {
-        *self = Vec1 {
-            x: self.x * other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> DivAssign<T> for Vec1<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: T) {
-        *self = Vec1 {
-            x: self.x / other,
-            ..Default::default()
-        }
-    }
-}
-
-// VecNum traits
-
-impl<T> VecNum for Vec1<T> where T: VecItem + Num {
-    fn sum(&self) -> Self::Item {
-        self.x
-    }
-
-    fn product(&self) -> Self::Item {
-        self.x
-    }
-}
-
-// VecInt traits
-
-impl<T> VecInt for Vec1<T> where T: VecItem + Integer {
-    fn div_euc(&self, other: Self) -> Self {
-        Self {
-            x: self.x.div_floor(&other.x),
-        }
-    }
+impl<T: VecItem> From<T> for Vec1<T> {
+    fn from(item: T) -> Self { Self { x: item } }
 }
 
-// VecSigned traits
-
-impl<T> VecSigned for Vec1<T> where T: VecItem + Signed {
-    fn snake_length(&self) -> Self::Item {
-        self.x.abs()
-    }
+impl<T: VecItem> From<[T; 1]> for Vec1<T> {
+    fn from(arr: [T; 1]) -> Self { Self { x: arr[0] } }
 }
 
-// VecFloat traits
-
-impl<T> VecFloat for Vec1<T> where T: VecItem + Float {
-    fn length(&self) -> Self::Item {
-        self.x
-    }
-
-    fn norm(&self) -> Self {
-        Vec1::new(
-            self.x,
-        )
-    }
+// Swizzle accessors
 
-    fn floor(&self) -> Self {
-        self.map(|e| e.floor())
-    }
+impl_swizzle_get!(Vec1 => Vec2 {
+    xx: x x;
+});
 
-    fn ceil(&self) -> Self {
-        self.map(|e| e.ceil())
-    }
+impl_swizzle_get!(Vec1 => Vec3 {
+    xxx: x x x;
+});
 
-    fn round(&self) -> Self {
-        self.map(|e| e.round())
-    }
-}
+impl_swizzle_get!(Vec1 => Vec4 {
+    xxxx: x x x x;
+});
@@ -1,23 +1,25 @@
 //! Functionality pertaining to `Vec2`
 
-use core::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
-use core::fmt;
+use num::Num;
 
-#[allow(unused_imports)]
-use num::{Num, Integer, Unsigned, Signed, Float};
-
-#[allow(unused_imports)]
-use super::{Vector, VecItem, VecNum, VecInt, VecUnsigned, VecSigned, VecFloat};
+use super::VecItem;
+use super::math::VecCross;
+use super::vecn::VecN;
+use super::{vec3::Vec3, vec4::Vec4};
 
+/// A 2-dimensional vector
 #[cfg(feature = "serialize")]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Vec2<T: VecItem> {
     pub x: T,
     pub y: T,
 }
 
+/// A 2-dimensional vector
 #[cfg(not(feature = "serialize"))]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+#[repr(C)]
 pub struct Vec2<T: VecItem> {
     pub x: T,
     pub y: T,
@@ -25,286 +27,99 @@ pub struct Vec2<T: VecItem> {
 
 impl<T: VecItem> Vec2<T> {
     /// Creates a new Vec2 from multiple components
-    pub fn new(x: T, y: T) -> Self { Self { x, y, ..Default::default() } }
-
-    /// Returns the elements of the vector as an array
-    pub fn elements(&self) -> [T; 2] { [self.x, self.y] }
-
-    /// Apply an operation to all elements of this vector, returning the result
-    pub fn map<U: VecItem, F: Fn(T) -> U>(&self, f: F) -> Vec2<U> {
-        Vec2 {
-            x: f(self.x),
-            y: f(self.y),
-        }
-    }
-
-    pub fn convert_to<U: VecItem + From<T>>(&self) -> Vec2<U> {
-        Vec2 {
-            x: U::from(self.x),
-            y: U::from(self.y),
-        }
-    }
-}
-
-impl<T: VecItem> Vector for Vec2<T> {
-    type Item = T;
-}
-
-// Debug and Display traits
-
-impl<T: VecItem + fmt::Debug> fmt::Debug for Vec2<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "(x: {:?}, y: {:?})", self.x, self.y)
-    }
-}
+    pub const fn new(x: T, y: T) -> Self { Self { x, y } }
 
-impl<T: VecItem + fmt::Display> fmt::Display for Vec2<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+    /// Creates a new Vec2 from an array of components
+    pub const fn from_array(arr: [T; 2]) -> Self {
+        let [x, y] = arr;
+        Self { x, y }
     }
-}
-
-// From traits
 
-impl<T: VecItem> From<[T; 2]> for Vec2<T> {
-    fn from(arr: [T; 2]) -> Self { Self { x: arr[0], y: arr[1], ..Default::default() } }
-}
-
-impl<T: VecItem> From<(T, T)> for Vec2<T> {
-    fn from(tup: (T, T)) -> Self { Self { x: tup.0, y: tup.1, ..Default::default() } }
-}
-
-// Op traits
-
-impl<T> Add for Vec2<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec2<T::Output>;
-    fn add(self, other: Self) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Sub for Vec2<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec2<T::Output>;
-    fn sub(self, other: Self) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Mul for Vec2<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec2<T::Output>;
-    fn mul(self, other: Self) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Div for Vec2<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec2<T::Output>;
-    fn div(self, other: Self) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x / other.x,
-            y: self.y / other.y,
-            ..Default::default()
-        }
-    }
-}
-
-// Op primitive traits
-
-impl<T> Add<T> for Vec2<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec2<T::Output>;
-    fn add(self, other: T) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x + other,
-            y: self.y + other,
-            ..Default::default()
-        }
-    }
-}
+    /// Returns the elements of the vector as an array
+    pub fn elements(&self) -> [T; 2] { [self.x, self.y] }
 
-impl<T> Sub<T> for Vec2<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec2<T::Output>;
-    fn sub(self, other: T) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x - other,
-            y: self.y - other,
-            ..Default::default()
-        }
+    /// Returns the elements of the vector as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // Safe because `Vec2<T>` is `#[repr(C)]` and has exactly the layout of `[T; 2]`
+        unsafe { &*(self as *const Self as *const [T; 2]) }
     }
-}
 
-impl<T> Mul<T> for Vec2<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec2<T::Output>;
-    fn mul(self, other: T) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x * other,
-            y: self.y * other,
-            ..Default::default()
-        }
+    /// Returns the elements of the vector as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safe because `Vec2<T>` is `#[repr(C)]` and has exactly the layout of `[T; 2]`
+        unsafe { &mut *(self as *mut Self as *mut [T; 2]) }
     }
-}
 
-impl<T> Div<T> for Vec2<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec2<T::Output>;
-    fn div(self, other: T) -> Vec2<T::Output> {
-        Vec2 {
-            x: self.x / other,
-            y: self.y / other,
-            ..Default::default()
-        }
-    }
+    /// Creates a new Vec3 from this vector and a z component
+    pub fn extend(&self, z: T) -> Vec3<T> { Vec3::new(self.x, self.y, z) }
 }
 
-// Assign operators
+impl<T: VecItem + Num> Vec2<T> {
+    /// Creates a new Vec2 of unit length along the x axis
+    pub fn unit_x() -> Self { Self::new(T::one(), T::zero()) }
 
-impl<T> AddAssign for Vec2<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: Self) {
-        *self = Vec2 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            ..Default::default()
-        }
-    }
-}
+    /// Creates a new Vec2 of unit length along the y axis
+    pub fn unit_y() -> Self { Self::new(T::zero(), T::one()) }
 
-impl<T> SubAssign for Vec2<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: Self) {
-        *self = Vec2 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            ..Default::default()
-        }
-    }
+    /// Calculates the 'perp-dot' product of the two vectors *(i.e: the z component of the 3-dimensional cross product)*
+    pub fn perp_dot(&self, other: Self) -> T { self.x * other.y - self.y * other.x }
 }
 
-impl<T> MulAssign for Vec2<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: Self) {
-        *self = Vec2 {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> DivAssign for Vec2<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: Self) {
-        *self = Vec2 {
-            x: self.x / other.x,
-            y: self.y / other.y,
-            ..Default::default()
-        }
-    }
-}
-
-// Assign primitive operators
+impl_vec!(Vec2, 2 [UNIT_X x 0, UNIT_Y y 1]);
 
-impl<T> AddAssign<T> for Vec2<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: T) {
-        *self = Vec2 {
-            x: self.x + other,
-            y: self.y + other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> SubAssign<T> for Vec2<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: T) {
-        *self = Vec2 {
-            x: self.x - other,
-            y: self.y - other,
-            ..Default::default()
-        }
-    }
-}
+// From traits
 
-impl<T> MulAssign<T> for Vec2<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: T) {
-        *self = Vec2 {
-            x: self.x * other,
-            y: self.y * other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<VecN<T, 2>> for Vec2<T> {
+    fn from(v: VecN<T, 2>) -> Self { Self::from_array(v.elements()) }
 }
 
-impl<T> DivAssign<T> for Vec2<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: T) {
-        *self = Vec2 {
-            x: self.x / other,
-            y: self.y / other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<Vec2<T>> for VecN<T, 2> {
+    fn from(v: Vec2<T>) -> Self { Self::from_array(v.elements()) }
 }
 
-// VecNum traits
-
-impl<T> VecNum for Vec2<T> where T: VecItem + Num {
-    fn sum(&self) -> Self::Item {
-        self.x + self.y
-    }
-
-    fn product(&self) -> Self::Item {
-        self.x * self.y
-    }
+impl<T: VecItem> From<[T; 2]> for Vec2<T> {
+    fn from(arr: [T; 2]) -> Self { Self { x: arr[0], y: arr[1] } }
 }
 
-// VecInt traits
-
-impl<T> VecInt for Vec2<T> where T: VecItem + Integer {
-    fn div_euc(&self, other: Self) -> Self {
-        Self {
-            x: self.x.div_floor(&other.x),
-            y: self.y.div_floor(&other.y),
-        }
-    }
+impl<T: VecItem> From<(T, T)> for Vec2<T> {
+    fn from(tup: (T, T)) -> Self { Self { x: tup.0, y: tup.1 } }
 }
 
-// VecSigned traits
+// Cross product
 
-impl<T> VecSigned for Vec2<T> where T: VecItem + Signed {
-    fn snake_length(&self) -> Self::Item {
-        self.x.abs() + self.y.abs()
-    }
+impl<T: VecItem + Num> VecCross for Vec2<T> {
+    type Output = T;
+    fn cross(&self, other: Self) -> T { self.perp_dot(other) }
 }
 
-// VecFloat traits
+// Swizzle accessors
 
-impl<T> VecFloat for Vec2<T> where T: VecItem + Float {
-    fn length(&self) -> Self::Item {
-        (self.x * self.x + self.y * self.y).sqrt()
-    }
+impl_swizzle_get!(Vec2 => Vec2 {
+    xx: x x; xy: x y;
+    yx: y x; yy: y y;
+});
 
-    fn norm(&self) -> Self {
-        let len = self.length();
-        Vec2::new(
-            self.x / len,
-            self.y / len,
-        )
-    }
+impl_swizzle_get!(Vec2 => Vec3 {
+    xxx: x x x; xxy: x x y;
+    xyx: x y x; xyy: x y y;
+    yxx: y x x; yxy: y x y;
+    yyx: y y x; yyy: y y y;
+});
 
-    fn floor(&self) -> Self {
-        self.map(|e| e.floor())
-    }
+impl_swizzle_get!(Vec2 => Vec4 {
+    xxxx: x x x x; xxxy: x x x y;
+    xxyx: x x y x; xxyy: x x y y;
+    xyxx: x y x x; xyxy: x y x y;
+    xyyx: x y y x; xyyy: x y y y;
+    yxxx: y x x x; yxxy: y x x y;
+    yxyx: y x y x; yxyy: y x y y;
+    yyxx: y y x x; yyxy: y y x y;
+    yyyx: y y y x; yyyy: y y y y;
+});
 
-    fn ceil(&self) -> Self {
-        self.map(|e| e.ceil())
-    }
+// Swizzle setters
 
-    fn round(&self) -> Self {
-        self.map(|e| e.round())
-    }
-}
+impl_swizzle_set!(Vec2 => Vec2 {
+    set_xy: x y;
+    set_yx: y x;
+});
@@ -1,24 +1,26 @@
 //! Functionality pertaining to `Vec3`
 
-use core::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
-use core::fmt;
+use num::Num;
 
-#[allow(unused_imports)]
-use num::{Num, Integer, Unsigned, Signed, Float};
-
-#[allow(unused_imports)]
-use super::{Vector, VecItem, VecNum, VecInt, VecUnsigned, VecSigned, VecFloat};
+use super::VecItem;
+use super::math::VecCross;
+use super::vecn::VecN;
+use super::{vec2::Vec2, vec4::Vec4};
 
+/// A 3-dimensional vector
 #[cfg(feature = "serialize")]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Vec3<T: VecItem> {
     pub x: T,
     pub y: T,
     pub z: T,
 }
 
+/// A 3-dimensional vector
 #[cfg(not(feature = "serialize"))]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+#[repr(C)]
 pub struct Vec3<T: VecItem> {
     pub x: T,
     pub y: T,
@@ -27,306 +29,154 @@ pub struct Vec3<T: VecItem> {
 
 impl<T: VecItem> Vec3<T> {
     /// Creates a new Vec3 from multiple components
-    pub fn new(x: T, y: T, z: T) -> Self { Self { x, y, z, ..Default::default() } }
-
-    /// Returns the elements of the vector as an array
-    pub fn elements(&self) -> [T; 3] { [self.x, self.y, self.z] }
-
-    /// Apply an operation to all elements of this vector, returning the result
-    pub fn map<U: VecItem, F: Fn(T) -> U>(&self, f: F) -> Vec3<U> {
-        Vec3 {
-            x: f(self.x),
-            y: f(self.y),
-            z: f(self.z),
-        }
-    }
+    pub const fn new(x: T, y: T, z: T) -> Self { Self { x, y, z } }
 
-    pub fn convert_to<U: VecItem + From<T>>(&self) -> Vec3<U> {
-        Vec3 {
-            x: U::from(self.x),
-            y: U::from(self.y),
-            z: U::from(self.z),
-        }
+    /// Creates a new Vec3 from an array of components
+    pub const fn from_array(arr: [T; 3]) -> Self {
+        let [x, y, z] = arr;
+        Self { x, y, z }
     }
-}
-
-impl<T: VecItem> Vector for Vec3<T> {
-    type Item = T;
-}
-
-// Debug and Display traits
 
-impl<T: VecItem + fmt::Debug> fmt::Debug for Vec3<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "(x: {:?}, y: {:?}, z: {:?})", self.x, self.y, self.z)
-    }
-}
-
-impl<T: VecItem + fmt::Display> fmt::Display for Vec3<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {}, {})", self.x, self.y, self.z)
-    }
-}
-
-// From traits
-
-impl<T: VecItem> From<[T; 3]> for Vec3<T> {
-    fn from(arr: [T; 3]) -> Self { Self { x: arr[0], y: arr[1], z: arr[2], ..Default::default() } }
-}
-
-impl<T: VecItem> From<(T, T, T)> for Vec3<T> {
-    fn from(tup: (T, T, T)) -> Self { Self { x: tup.0, y: tup.1, z: tup.2, ..Default::default() } }
-}
-
-// Op traits
-
-impl<T> Add for Vec3<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec3<T::Output>;
-    fn add(self, other: Self) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Sub for Vec3<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec3<T::Output>;
-    fn sub(self, other: Self) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Mul for Vec3<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec3<T::Output>;
-    fn mul(self, other: Self) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            z: self.z * other.z,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Div for Vec3<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec3<T::Output>;
-    fn div(self, other: Self) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x / other.x,
-            y: self.y / other.y,
-            z: self.z / other.z,
-            ..Default::default()
-        }
-    }
-}
+    /// Returns the elements of the vector as an array
+    pub fn elements(&self) -> [T; 3] { [self.x, self.y, self.z] }
 
-// Op primitive traits
-
-impl<T> Add<T> for Vec3<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec3<T::Output>;
-    fn add(self, other: T) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x + other,
-            y: self.y + other,
-            z: self.z + other,
-            ..Default::default()
-        }
+    /// Returns the elements of the vector as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // Safe because `Vec3<T>` is `#[repr(C)]` and has exactly the layout of `[T; 3]`
+        unsafe { &*(self as *const Self as *const [T; 3]) }
     }
-}
 
-impl<T> Sub<T> for Vec3<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec3<T::Output>;
-    fn sub(self, other: T) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x - other,
-            y: self.y - other,
-            z: self.z - other,
-            ..Default::default()
-        }
+    /// Returns the elements of the vector as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safe because `Vec3<T>` is `#[repr(C)]` and has exactly the layout of `[T; 3]`
+        unsafe { &mut *(self as *mut Self as *mut [T; 3]) }
     }
-}
 
-impl<T> Mul<T> for Vec3<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec3<T::Output>;
-    fn mul(self, other: T) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other,
-            ..Default::default()
-        }
-    }
-}
+    /// Creates a new Vec4 from this vector and a w component
+    pub fn extend(&self, w: T) -> Vec4<T> { Vec4::new(self.x, self.y, self.z, w) }
 
-impl<T> Div<T> for Vec3<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec3<T::Output>;
-    fn div(self, other: T) -> Vec3<T::Output> {
-        Vec3 {
-            x: self.x / other,
-            y: self.y / other,
-            z: self.z / other,
-            ..Default::default()
-        }
-    }
+    /// Creates a new Vec2 from the x and y components of this vector
+    pub fn truncate(&self) -> Vec2<T> { Vec2::new(self.x, self.y) }
 }
 
-// Assign operators
+impl<T: VecItem + Num> Vec3<T> {
+    /// Creates a new Vec3 of unit length along the x axis
+    pub fn unit_x() -> Self { Self::new(T::one(), T::zero(), T::zero()) }
 
-impl<T> AddAssign for Vec3<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: Self) {
-        *self = Vec3 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-            ..Default::default()
-        }
-    }
-}
+    /// Creates a new Vec3 of unit length along the y axis
+    pub fn unit_y() -> Self { Self::new(T::zero(), T::one(), T::zero()) }
 
-impl<T> SubAssign for Vec3<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: Self) {
-        *self = Vec3 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-            ..Default::default()
-        }
-    }
-}
+    /// Creates a new Vec3 of unit length along the z axis
+    pub fn unit_z() -> Self { Self::new(T::zero(), T::zero(), T::one()) }
 
-impl<T> MulAssign for Vec3<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: Self) {
-        *self = Vec3 {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            z: self.z * other.z,
-            ..Default::default()
-        }
-    }
-}
+    /// Converts the vector, as a point, into homogeneous coordinates *(i.e: with a w component of 1)*
+    pub fn to_homogeneous_point(&self) -> Vec4<T> { self.extend(T::one()) }
 
-impl<T> DivAssign for Vec3<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: Self) {
-        *self = Vec3 {
-            x: self.x / other.x,
-            y: self.y / other.y,
-            z: self.z / other.z,
-            ..Default::default()
-        }
-    }
+    /// Converts the vector, as a direction, into homogeneous coordinates *(i.e: with a w component of 0)*
+    pub fn to_homogeneous_dir(&self) -> Vec4<T> { self.extend(T::zero()) }
 }
 
-// Assign primitive operators
+impl_vec!(Vec3, 3 [UNIT_X x 0, UNIT_Y y 1, UNIT_Z z 2]);
 
-impl<T> AddAssign<T> for Vec3<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: T) {
-        *self = Vec3 {
-            x: self.x + other,
-            y: self.y + other,
-            z: self.z + other,
-            ..Default::default()
-        }
-    }
-}
+// From traits
 
-impl<T> SubAssign<T> for Vec3<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: T) {
-        *self = Vec3 {
-            x: self.x - other,
-            y: self.y - other,
-            z: self.z - other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<VecN<T, 3>> for Vec3<T> {
+    fn from(v: VecN<T, 3>) -> Self { Self::from_array(v.elements()) }
 }
 
-impl<T> MulAssign<T> for Vec3<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: T) {
-        *self = Vec3 {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<Vec3<T>> for VecN<T, 3> {
+    fn from(v: Vec3<T>) -> Self { Self::from_array(v.elements()) }
 }
 
-impl<T> DivAssign<T> for Vec3<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: T) {
-        *self = Vec3 {
-            x: self.x / other,
-            y: self.y / other,
-            z: self.z / other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<[T; 3]> for Vec3<T> {
+    fn from(arr: [T; 3]) -> Self { Self { x: arr[0], y: arr[1], z: arr[2] } }
 }
 
-// VecNum traits
-
-impl<T> VecNum for Vec3<T> where T: VecItem + Num {
-    fn sum(&self) -> Self::Item {
-        self.x + self.y + self.z
-    }
-
-    fn product(&self) -> Self::Item {
-        self.x * self.y * self.z
-    }
+impl<T: VecItem> From<(T, T, T)> for Vec3<T> {
+    fn from(tup: (T, T, T)) -> Self { Self { x: tup.0, y: tup.1, z: tup.2 } }
 }
 
-// VecInt traits
-
-impl<T> VecInt for Vec3<T> where T: VecItem + Integer {
-    fn div_euc(&self, other: Self) -> Self {
-        Self {
-            x: self.x.div_floor(&other.x),
-            y: self.y.div_floor(&other.y),
-            z: self.z.div_floor(&other.z),
-        }
-    }
+impl<T: VecItem> From<(Vec2<T>, T)> for Vec3<T> {
+    fn from(tup: (Vec2<T>, T)) -> Self { Self::new(tup.0.x, tup.0.y, tup.1) }
 }
 
-// VecSigned traits
-
-impl<T> VecSigned for Vec3<T> where T: VecItem + Signed {
-    fn snake_length(&self) -> Self::Item {
-        self.x.abs() + self.y.abs() + self.z.abs()
-    }
+impl<T: VecItem> From<(T, Vec2<T>)> for Vec3<T> {
+    fn from(tup: (T, Vec2<T>)) -> Self { Self::new(tup.0, tup.1.x, tup.1.y) }
 }
 
-// VecFloat traits
+// Cross product
 
-impl<T> VecFloat for Vec3<T> where T: VecItem + Float {
-    fn length(&self) -> Self::Item {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
-    }
-
-    fn norm(&self) -> Self {
-        let len = self.length();
-        Vec3::new(
-            self.x / len,
-            self.y / len,
-            self.z / len,
+impl<T: VecItem + Num> VecCross for Vec3<T> {
+    type Output = Self;
+    fn cross(&self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
         )
     }
-
-    fn floor(&self) -> Self {
-        self.map(|e| e.floor())
-    }
-
-    fn ceil(&self) -> Self {
-        self.map(|e| e.ceil())
-    }
-
-    fn round(&self) -> Self {
-        self.map(|e| e.round())
-    }
 }
+
+// Swizzle accessors
+
+impl_swizzle_get!(Vec3 => Vec2 {
+    xx: x x; xy: x y; xz: x z;
+    yx: y x; yy: y y; yz: y z;
+    zx: z x; zy: z y; zz: z z;
+});
+
+impl_swizzle_get!(Vec3 => Vec3 {
+    xxx: x x x; xxy: x x y; xxz: x x z;
+    xyx: x y x; xyy: x y y; xyz: x y z;
+    xzx: x z x; xzy: x z y; xzz: x z z;
+    yxx: y x x; yxy: y x y; yxz: y x z;
+    yyx: y y x; yyy: y y y; yyz: y y z;
+    yzx: y z x; yzy: y z y; yzz: y z z;
+    zxx: z x x; zxy: z x y; zxz: z x z;
+    zyx: z y x; zyy: z y y; zyz: z y z;
+    zzx: z z x; zzy: z z y; zzz: z z z;
+});
+
+impl_swizzle_get!(Vec3 => Vec4 {
+    xxxx: x x x x; xxxy: x x x y; xxxz: x x x z;
+    xxyx: x x y x; xxyy: x x y y; xxyz: x x y z;
+    xxzx: x x z x; xxzy: x x z y; xxzz: x x z z;
+    xyxx: x y x x; xyxy: x y x y; xyxz: x y x z;
+    xyyx: x y y x; xyyy: x y y y; xyyz: x y y z;
+    xyzx: x y z x; xyzy: x y z y; xyzz: x y z z;
+    xzxx: x z x x; xzxy: x z x y; xzxz: x z x z;
+    xzyx: x z y x; xzyy: x z y y; xzyz: x z y z;
+    xzzx: x z z x; xzzy: x z z y; xzzz: x z z z;
+    yxxx: y x x x; yxxy: y x x y; yxxz: y x x z;
+    yxyx: y x y x; yxyy: y x y y; yxyz: y x y z;
+    yxzx: y x z x; yxzy: y x z y; yxzz: y x z z;
+    yyxx: y y x x; yyxy: y y x y; yyxz: y y x z;
+    yyyx: y y y x; yyyy: y y y y; yyyz: y y y z;
+    yyzx: y y z x; yyzy: y y z y; yyzz: y y z z;
+    yzxx: y z x x; yzxy: y z x y; yzxz: y z x z;
+    yzyx: y z y x; yzyy: y z y y; yzyz: y z y z;
+    yzzx: y z z x; yzzy: y z z y; yzzz: y z z z;
+    zxxx: z x x x; zxxy: z x x y; zxxz: z x x z;
+    zxyx: z x y x; zxyy: z x y y; zxyz: z x y z;
+    zxzx: z x z x; zxzy: z x z y; zxzz: z x z z;
+    zyxx: z y x x; zyxy: z y x y; zyxz: z y x z;
+    zyyx: z y y x; zyyy: z y y y; zyyz: z y y z;
+    zyzx: z y z x; zyzy: z y z y; zyzz: z y z z;
+    zzxx: z z x x; zzxy: z z x y; zzxz: z z x z;
+    zzyx: z z y x; zzyy: z z y y; zzyz: z z y z;
+    zzzx: z z z x; zzzy: z z z y; zzzz: z z z z;
+});
+
+// Swizzle setters
+
+impl_swizzle_set!(Vec3 => Vec2 {
+    set_xy: x y; set_xz: x z;
+    set_yx: y x; set_yz: y z;
+    set_zx: z x; set_zy: z y;
+});
+
+impl_swizzle_set!(Vec3 => Vec3 {
+    set_xyz: x y z; set_xzy: x z y;
+    set_yxz: y x z; set_yzx: y z x;
+    set_zxy: z x y; set_zyx: z y x;
+});
@@ -1,16 +1,15 @@
 //! Functionality pertaining to `Vec4`
 
-use core::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
-use core::fmt;
+use num::{Num, Float};
 
-#[allow(unused_imports)]
-use num::{Num, Integer, Unsigned, Signed, Float};
-
-#[allow(unused_imports)]
-use super::{Vector, VecItem, VecNum, VecInt, VecUnsigned, VecSigned, VecFloat};
+use super::VecItem;
+use super::vecn::VecN;
+use super::{vec2::Vec2, vec3::Vec3};
 
+/// A 4-dimensional vector
 #[cfg(feature = "serialize")]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Vec4<T: VecItem> {
     pub x: T,
     pub y: T,
@@ -18,8 +17,10 @@ pub struct Vec4<T: VecItem> {
     pub w: T,
 }
 
+/// A 4-dimensional vector
 #[cfg(not(feature = "serialize"))]
 #[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+#[repr(C)]
 pub struct Vec4<T: VecItem> {
     pub x: T,
     pub y: T,
@@ -28,327 +29,238 @@ pub struct Vec4<T: VecItem> {
 }
 
 impl<T: VecItem> Vec4<T> {
-    /// Creates a new Vec3 from multiple components
-    pub fn new(x: T, y: T, z: T, w: T) -> Self { Self { x, y, z, w, ..Default::default() } }
-
-    /// Returns the elements of the vector as an array
-    pub fn elements(&self) -> [T; 4] { [self.x, self.y, self.z, self.w] }
+    /// Creates a new Vec4 from multiple components
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self { Self { x, y, z, w } }
 
-    /// Apply an operation to all elements of this vector, returning the result
-    pub fn map<U: VecItem, F: Fn(T) -> U>(&self, f: F) -> Vec4<U> {
-        Vec4 {
-            x: f(self.x),
-            y: f(self.y),
-            z: f(self.z),
-            w: f(self.w),
-        }
+    /// Creates a new Vec4 from an array of components
+    pub const fn from_array(arr: [T; 4]) -> Self {
+        let [x, y, z, w] = arr;
+        Self { x, y, z, w }
     }
 
-    pub fn convert_to<U: VecItem + From<T>>(&self) -> Vec4<U> {
-        Vec4 {
-            x: U::from(self.x),
-            y: U::from(self.y),
-            z: U::from(self.z),
-            w: U::from(self.w),
-        }
-    }
-}
-
-impl<T: VecItem> Vector for Vec4<T> {
-    type Item = T;
-}
-
-// Debug and Display traits
+    /// Returns the elements of the vector as an array
+    pub fn elements(&self) -> [T; 4] { [self.x, self.y, self.z, self.w] }
 
-impl<T: VecItem + fmt::Debug> fmt::Debug for Vec4<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "(x: {:?}, y: {:?}, z: {:?}, w: {:?})", self.x, self.y, self.z, self.w)
+    /// Returns the elements of the vector as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // Safe because `Vec4<T>` is `#[repr(C)]` and has exactly the layout of `[T; 4]`
+        unsafe { &*(self as *const Self as *const [T; 4]) }
     }
-}
 
-impl<T: VecItem + fmt::Display> fmt::Display for Vec4<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    /// Returns the elements of the vector as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safe because `Vec4<T>` is `#[repr(C)]` and has exactly the layout of `[T; 4]`
+        unsafe { &mut *(self as *mut Self as *mut [T; 4]) }
     }
-}
-
-// From traits
 
-impl<T: VecItem> From<[T; 4]> for Vec4<T> {
-    fn from(arr: [T; 4]) -> Self { Self { x: arr[0], y: arr[1], z: arr[2], w: arr[3], ..Default::default() } }
+    /// Creates a new Vec3 from the x, y and z components of this vector
+    pub fn truncate(&self) -> Vec3<T> { Vec3::new(self.x, self.y, self.z) }
 }
 
-impl<T: VecItem> From<(T, T, T, T)> for Vec4<T> {
-    fn from(tup: (T, T, T, T)) -> Self { Self { x: tup.0, y: tup.1, z: tup.2, w: tup.3, ..Default::default() } }
-}
-
-// Op traits
-
-impl<T> Add for Vec4<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec4<T::Output>;
-    fn add(self, other: Self) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-            w: self.w + other.w,
-            ..Default::default()
+impl<T: VecItem + Float> Vec4<T> {
+    /// Converts the vector, as a point in homogeneous coordinates, into a Vec3 by dividing it by its w component,
+    /// returning `None` if the w component is approximately zero
+    ///
+    /// *The w component is considered approximately zero when it is no greater than `T::epsilon()` relative to the
+    /// largest component of the vector, so that points far from the origin are not mistaken for directions*
+    pub fn from_homogeneous(&self) -> Option<Vec3<T>> {
+        let scale = self.iter().fold(T::zero(), |max, e| max.max(e.abs()));
+        if self.w.abs() <= T::epsilon() * scale {
+            None
+        } else {
+            Some(self.truncate() / self.w)
         }
     }
 }
 
-impl<T> Sub for Vec4<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec4<T::Output>;
-    fn sub(self, other: Self) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-            w: self.w - other.w,
-            ..Default::default()
-        }
-    }
-}
+impl<T: VecItem + Num> Vec4<T> {
+    /// Creates a new Vec4 of unit length along the x axis
+    pub fn unit_x() -> Self { Self::new(T::one(), T::zero(), T::zero(), T::zero()) }
 
-impl<T> Mul for Vec4<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec4<T::Output>;
-    fn mul(self, other: Self) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            z: self.z * other.z,
-            w: self.w * other.w,
-            ..Default::default()
-        }
-    }
-}
+    /// Creates a new Vec4 of unit length along the y axis
+    pub fn unit_y() -> Self { Self::new(T::zero(), T::one(), T::zero(), T::zero()) }
 
-impl<T> Div for Vec4<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec4<T::Output>;
-    fn div(self, other: Self) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x / other.x,
-            y: self.y / other.y,
-            z: self.z / other.z,
-            w: self.w / other.w,
-            ..Default::default()
-        }
-    }
-}
+    /// Creates a new Vec4 of unit length along the z axis
+    pub fn unit_z() -> Self { Self::new(T::zero(), T::zero(), T::one(), T::zero()) }
 
-// Op primitive traits
-
-impl<T> Add<T> for Vec4<T> where T: VecItem + Add, T::Output: VecItem + Add {
-    type Output = Vec4<T::Output>;
-    fn add(self, other: T) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x + other,
-            y: self.y + other,
-            z: self.z + other,
-            w: self.w + other,
-            ..Default::default()
-        }
-    }
+    /// Creates a new Vec4 of unit length along the w axis
+    pub fn unit_w() -> Self { Self::new(T::zero(), T::zero(), T::zero(), T::one()) }
 }
 
-impl<T> Sub<T> for Vec4<T> where T: VecItem + Sub, T::Output: VecItem + Sub {
-    type Output = Vec4<T::Output>;
-    fn sub(self, other: T) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x - other,
-            y: self.y - other,
-            z: self.z - other,
-            w: self.w - other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Mul<T> for Vec4<T> where T: VecItem + Mul, T::Output: VecItem + Mul {
-    type Output = Vec4<T::Output>;
-    fn mul(self, other: T) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other,
-            w: self.w * other,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> Div<T> for Vec4<T> where T: VecItem + Div, T::Output: VecItem + Div {
-    type Output = Vec4<T::Output>;
-    fn div(self, other: T) -> Vec4<T::Output> {
-        Vec4 {
-            x: self.x / other,
-            y: self.y / other,
-            z: self.z / other,
-            w: self.w / other,
-            ..Default::default()
-        }
-    }
-}
+impl_vec!(Vec4, 4 [UNIT_X x 0, UNIT_Y y 1, UNIT_Z z 2, UNIT_W w 3]);
 
-// Assign operators
+// From traits
 
-impl<T> AddAssign for Vec4<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: Self) {
-        *self = Vec4 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-            w: self.w + other.w,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<VecN<T, 4>> for Vec4<T> {
+    fn from(v: VecN<T, 4>) -> Self { Self::from_array(v.elements()) }
 }
 
-impl<T> SubAssign for Vec4<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: Self) {
-        *self = Vec4 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-            w: self.w - other.w,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<Vec4<T>> for VecN<T, 4> {
+    fn from(v: Vec4<T>) -> Self { Self::from_array(v.elements()) }
 }
 
-impl<T> MulAssign for Vec4<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: Self) {
-        *self = Vec4 {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            z: self.z * other.z,
-            w: self.w * other.w,
-            ..Default::default()
-        }
-    }
-}
-
-impl<T> DivAssign for Vec4<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: Self) {
-        *self = Vec4 {
-            x: self.x / other.x,
-            y: self.y / other.y,
-            z: self.z / other.z,
-            w: self.w / other.w,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<[T; 4]> for Vec4<T> {
+    fn from(arr: [T; 4]) -> Self { Self { x: arr[0], y: arr[1], z: arr[2], w: arr[3] } }
 }
 
-// Assign primitive operators
-
-impl<T> AddAssign<T> for Vec4<T> where T: VecItem + Add<Output=T> {
-    fn add_assign(&mut self, other: T) {
-        *self = Vec4 {
-            x: self.x + other,
-            y: self.y + other,
-            z: self.z + other,
-            w: self.w + other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<(T, T, T, T)> for Vec4<T> {
+    fn from(tup: (T, T, T, T)) -> Self { Self { x: tup.0, y: tup.1, z: tup.2, w: tup.3 } }
 }
 
-impl<T> SubAssign<T> for Vec4<T> where T: VecItem + Sub<Output=T> {
-    fn sub_assign(&mut self, other: T) {
-        *self = Vec4 {
-            x: self.x - other,
-            y: self.y - other,
-            z: self.z - other,
-            w: self.w - other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<(Vec3<T>, T)> for Vec4<T> {
+    fn from(tup: (Vec3<T>, T)) -> Self { Self::new(tup.0.x, tup.0.y, tup.0.z, tup.1) }
 }
 
-impl<T> MulAssign<T> for Vec4<T> where T: VecItem + Mul<Output=T> {
-    fn mul_assign(&mut self, other: T) {
-        *self = Vec4 {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other,
-            w: self.w * other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<(T, Vec3<T>)> for Vec4<T> {
+    fn from(tup: (T, Vec3<T>)) -> Self { Self::new(tup.0, tup.1.x, tup.1.y, tup.1.z) }
 }
 
-impl<T> DivAssign<T> for Vec4<T> where T: VecItem + Div<Output=T> {
-    fn div_assign(&mut self, other: T) {
-        *self = Vec4 {
-            x: self.x / other,
-            y: self.y / other,
-            z: self.z / other,
-            w: self.w / other,
-            ..Default::default()
-        }
-    }
+impl<T: VecItem> From<(Vec2<T>, Vec2<T>)> for Vec4<T> {
+    fn from(tup: (Vec2<T>, Vec2<T>)) -> Self { Self::new(tup.0.x, tup.0.y, tup.1.x, tup.1.y) }
 }
 
-// VecNum traits
-
-impl<T> VecNum for Vec4<T> where T: VecItem + Num {
-    fn sum(&self) -> Self::Item {
-        self.x + self.y + self.z + self.w
-    }
-
-    fn product(&self) -> Self::Item {
-        self.x * self.y * self.z * self.w
-    }
+impl<T: VecItem> From<(Vec2<T>, T, T)> for Vec4<T> {
+    fn from(tup: (Vec2<T>, T, T)) -> Self { Self::new(tup.0.x, tup.0.y, tup.1, tup.2) }
 }
 
-// VecInt traits
-
-impl<T> VecInt for Vec4<T> where T: VecItem + Integer {
-    fn div_euc(&self, other: Self) -> Self {
-        Self {
-            x: self.x.div_floor(&other.x),
-            y: self.y.div_floor(&other.y),
-            z: self.z.div_floor(&other.z),
-            w: self.w.div_floor(&other.w),
-        }
-    }
+impl<T: VecItem> From<(T, Vec2<T>, T)> for Vec4<T> {
+    fn from(tup: (T, Vec2<T>, T)) -> Self { Self::new(tup.0, tup.1.x, tup.1.y, tup.2) }
 }
 
-// VecSigned traits
-
-impl<T> VecSigned for Vec4<T> where T: VecItem + Signed {
-    fn snake_length(&self) -> Self::Item {
-        self.x.abs() + self.y.abs() + self.z.abs() + self.w.abs()
-    }
+impl<T: VecItem> From<(T, T, Vec2<T>)> for Vec4<T> {
+    fn from(tup: (T, T, Vec2<T>)) -> Self { Self::new(tup.0, tup.1, tup.2.x, tup.2.y) }
 }
 
-// VecFloat traits
-
-impl<T> VecFloat for Vec4<T> where T: VecItem + Float {
-    fn length(&self) -> Self::Item {
-        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
-    }
-
-    fn norm(&self) -> Self {
-        let len = self.length();
-        Vec4::new(
-            self.x / len,
-            self.y / len,
-            self.z / len,
-            self.w / len,
-        )
-    }
-
-    fn floor(&self) -> Self {
-        self.map(|e| e.floor())
-    }
-
-    fn ceil(&self) -> Self {
-        self.map(|e| e.ceil())
-    }
-
-    fn round(&self) -> Self {
-        self.map(|e| e.round())
-    }
-}
+// Swizzle accessors
+
+impl_swizzle_get!(Vec4 => Vec2 {
+    xx: x x; xy: x y; xz: x z; xw: x w;
+    yx: y x; yy: y y; yz: y z; yw: y w;
+    zx: z x; zy: z y; zz: z z; zw: z w;
+    wx: w x; wy: w y; wz: w z; ww: w w;
+});
+
+impl_swizzle_get!(Vec4 => Vec3 {
+    xxx: x x x; xxy: x x y; xxz: x x z; xxw: x x w;
+    xyx: x y x; xyy: x y y; xyz: x y z; xyw: x y w;
+    xzx: x z x; xzy: x z y; xzz: x z z; xzw: x z w;
+    xwx: x w x; xwy: x w y; xwz: x w z; xww: x w w;
+    yxx: y x x; yxy: y x y; yxz: y x z; yxw: y x w;
+    yyx: y y x; yyy: y y y; yyz: y y z; yyw: y y w;
+    yzx: y z x; yzy: y z y; yzz: y z z; yzw: y z w;
+    ywx: y w x; ywy: y w y; ywz: y w z; yww: y w w;
+    zxx: z x x; zxy: z x y; zxz: z x z; zxw: z x w;
+    zyx: z y x; zyy: z y y; zyz: z y z; zyw: z y w;
+    zzx: z z x; zzy: z z y; zzz: z z z; zzw: z z w;
+    zwx: z w x; zwy: z w y; zwz: z w z; zww: z w w;
+    wxx: w x x; wxy: w x y; wxz: w x z; wxw: w x w;
+    wyx: w y x; wyy: w y y; wyz: w y z; wyw: w y w;
+    wzx: w z x; wzy: w z y; wzz: w z z; wzw: w z w;
+    wwx: w w x; wwy: w w y; wwz: w w z; www: w w w;
+});
+
+impl_swizzle_get!(Vec4 => Vec4 {
+    xxxx: x x x x; xxxy: x x x y; xxxz: x x x z; xxxw: x x x w;
+    xxyx: x x y x; xxyy: x x y y; xxyz: x x y z; xxyw: x x y w;
+    xxzx: x x z x; xxzy: x x z y; xxzz: x x z z; xxzw: x x z w;
+    xxwx: x x w x; xxwy: x x w y; xxwz: x x w z; xxww: x x w w;
+    xyxx: x y x x; xyxy: x y x y; xyxz: x y x z; xyxw: x y x w;
+    xyyx: x y y x; xyyy: x y y y; xyyz: x y y z; xyyw: x y y w;
+    xyzx: x y z x; xyzy: x y z y; xyzz: x y z z; xyzw: x y z w;
+    xywx: x y w x; xywy: x y w y; xywz: x y w z; xyww: x y w w;
+    xzxx: x z x x; xzxy: x z x y; xzxz: x z x z; xzxw: x z x w;
+    xzyx: x z y x; xzyy: x z y y; xzyz: x z y z; xzyw: x z y w;
+    xzzx: x z z x; xzzy: x z z y; xzzz: x z z z; xzzw: x z z w;
+    xzwx: x z w x; xzwy: x z w y; xzwz: x z w z; xzww: x z w w;
+    xwxx: x w x x; xwxy: x w x y; xwxz: x w x z; xwxw: x w x w;
+    xwyx: x w y x; xwyy: x w y y; xwyz: x w y z; xwyw: x w y w;
+    xwzx: x w z x; xwzy: x w z y; xwzz: x w z z; xwzw: x w z w;
+    xwwx: x w w x; xwwy: x w w y; xwwz: x w w z; xwww: x w w w;
+    yxxx: y x x x; yxxy: y x x y; yxxz: y x x z; yxxw: y x x w;
+    yxyx: y x y x; yxyy: y x y y; yxyz: y x y z; yxyw: y x y w;
+    yxzx: y x z x; yxzy: y x z y; yxzz: y x z z; yxzw: y x z w;
+    yxwx: y x w x; yxwy: y x w y; yxwz: y x w z; yxww: y x w w;
+    yyxx: y y x x; yyxy: y y x y; yyxz: y y x z; yyxw: y y x w;
+    yyyx: y y y x; yyyy: y y y y; yyyz: y y y z; yyyw: y y y w;
+    yyzx: y y z x; yyzy: y y z y; yyzz: y y z z; yyzw: y y z w;
+    yywx: y y w x; yywy: y y w y; yywz: y y w z; yyww: y y w w;
+    yzxx: y z x x; yzxy: y z x y; yzxz: y z x z; yzxw: y z x w;
+    yzyx: y z y x; yzyy: y z y y; yzyz: y z y z; yzyw: y z y w;
+    yzzx: y z z x; yzzy: y z z y; yzzz: y z z z; yzzw: y z z w;
+    yzwx: y z w x; yzwy: y z w y; yzwz: y z w z; yzww: y z w w;
+    ywxx: y w x x; ywxy: y w x y; ywxz: y w x z; ywxw: y w x w;
+    ywyx: y w y x; ywyy: y w y y; ywyz: y w y z; ywyw: y w y w;
+    ywzx: y w z x; ywzy: y w z y; ywzz: y w z z; ywzw: y w z w;
+    ywwx: y w w x; ywwy: y w w y; ywwz: y w w z; ywww: y w w w;
+    zxxx: z x x x; zxxy: z x x y; zxxz: z x x z; zxxw: z x x w;
+    zxyx: z x y x; zxyy: z x y y; zxyz: z x y z; zxyw: z x y w;
+    zxzx: z x z x; zxzy: z x z y; zxzz: z x z z; zxzw: z x z w;
+    zxwx: z x w x; zxwy: z x w y; zxwz: z x w z; zxww: z x w w;
+    zyxx: z y x x; zyxy: z y x y; zyxz: z y x z; zyxw: z y x w;
+    zyyx: z y y x; zyyy: z y y y; zyyz: z y y z; zyyw: z y y w;
+    zyzx: z y z x; zyzy: z y z y; zyzz: z y z z; zyzw: z y z w;
+    zywx: z y w x; zywy: z y w y; zywz: z y w z; zyww: z y w w;
+    zzxx: z z x x; zzxy: z z x y; zzxz: z z x z; zzxw: z z x w;
+    zzyx: z z y x; zzyy: z z y y; zzyz: z z y z; zzyw: z z y w;
+    zzzx: z z z x; zzzy: z z z y; zzzz: z z z z; zzzw: z z z w;
+    zzwx: z z w x; zzwy: z z w y; zzwz: z z w z; zzww: z z w w;
+    zwxx: z w x x; zwxy: z w x y; zwxz: z w x z; zwxw: z w x w;
+    zwyx: z w y x; zwyy: z w y y; zwyz: z w y z; zwyw: z w y w;
+    zwzx: z w z x; zwzy: z w z y; zwzz: z w z z; zwzw: z w z w;
+    zwwx: z w w x; zwwy: z w w y; zwwz: z w w z; zwww: z w w w;
+    wxxx: w x x x; wxxy: w x x y; wxxz: w x x z; wxxw: w x x w;
+    wxyx: w x y x; wxyy: w x y y; wxyz: w x y z; wxyw: w x y w;
+    wxzx: w x z x; wxzy: w x z y; wxzz: w x z z; wxzw: w x z w;
+    wxwx: w x w x; wxwy: w x w y; wxwz: w x w z; wxww: w x w w;
+    wyxx: w y x x; wyxy: w y x y; wyxz: w y x z; wyxw: w y x w;
+    wyyx: w y y x; wyyy: w y y y; wyyz: w y y z; wyyw: w y y w;
+    wyzx: w y z x; wyzy: w y z y; wyzz: w y z z; wyzw: w y z w;
+    wywx: w y w x; wywy: w y w y; wywz: w y w z; wyww: w y w w;
+    wzxx: w z x x; wzxy: w z x y; wzxz: w z x z; wzxw: w z x w;
+    wzyx: w z y x; wzyy: w z y y; wzyz: w z y z; wzyw: w z y w;
+    wzzx: w z z x; wzzy: w z z y; wzzz: w z z z; wzzw: w z z w;
+    wzwx: w z w x; wzwy: w z w y; wzwz: w z w z; wzww: w z w w;
+    wwxx: w w x x; wwxy: w w x y; wwxz: w w x z; wwxw: w w x w;
+    wwyx: w w y x; wwyy: w w y y; wwyz: w w y z; wwyw: w w y w;
+    wwzx: w w z x; wwzy: w w z y; wwzz: w w z z; wwzw: w w z w;
+    wwwx: w w w x; wwwy: w w w y; wwwz: w w w z; wwww: w w w w;
+});
+
+// Swizzle setters
+
+impl_swizzle_set!(Vec4 => Vec2 {
+    set_xy: x y; set_xz: x z; set_xw: x w;
+    set_yx: y x; set_yz: y z; set_yw: y w;
+    set_zx: z x; set_zy: z y; set_zw: z w;
+    set_wx: w x; set_wy: w y; set_wz: w z;
+});
+
+impl_swizzle_set!(Vec4 => Vec3 {
+    set_xyz: x y z; set_xyw: x y w;
+    set_xzy: x z y; set_xzw: x z w;
+    set_xwy: x w y; set_xwz: x w z;
+    set_yxz: y x z; set_yxw: y x w;
+    set_yzx: y z x; set_yzw: y z w;
+    set_ywx: y w x; set_ywz: y w z;
+    set_zxy: z x y; set_zxw: z x w;
+    set_zyx: z y x; set_zyw: z y w;
+    set_zwx: z w x; set_zwy: z w y;
+    set_wxy: w x y; set_wxz: w x z;
+    set_wyx: w y x; set_wyz: w y z;
+    set_wzx: w z x; set_wzy: w z y;
+});
+
+impl_swizzle_set!(Vec4 => Vec4 {
+    set_xyzw: x y z w; set_xywz: x y w z;
+    set_xzyw: x z y w; set_xzwy: x z w y;
+    set_xwyz: x w y z; set_xwzy: x w z y;
+    set_yxzw: y x z w; set_yxwz: y x w z;
+    set_yzxw: y z x w; set_yzwx: y z w x;
+    set_ywxz: y w x z; set_ywzx: y w z x;
+    set_zxyw: z x y w; set_zxwy: z x w y;
+    set_zyxw: z y x w; set_zywx: z y w x;
+    set_zwxy: z w x y; set_zwyx: z w y x;
+    set_wxyz: w x y z; set_wxzy: w x z y;
+    set_wyxz: w y x z; set_wyzx: w y z x;
+    set_wzxy: w z x y; set_wzyx: w z y x;
+});
@@ -0,0 +1,96 @@
+//! Functionality pertaining to `VecN`, the N-dimensional vector type
+
+use super::VecItem;
+
+/// An N-dimensional vector
+///
+/// `VecN` provides the same functionality as `Vec1`, `Vec2`, `Vec3` and `Vec4` for any number of components. Vectors
+/// of one to four components convert to and from their named equivalents with `From`.
+///
+/// # Layout
+///
+/// `VecN<T, N>` is `#[repr(C)]` and has exactly the layout of `[T; N]`: its components are stored consecutively, in
+/// order, with no padding. Slices of vectors may therefore be reinterpreted as slices of their components and back
+/// (see `.flatten_slice()` and `.from_flat_slice()`).
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+#[repr(C)]
+pub struct VecN<T: VecItem, const N: usize> {
+    data: [T; N],
+}
+
+impl<T: VecItem, const N: usize> VecN<T, N> {
+    /// Creates a new VecN from an array of components
+    pub const fn from_array(data: [T; N]) -> Self { Self { data } }
+
+    /// Returns the elements of the vector as an array
+    pub fn elements(&self) -> [T; N] { self.data }
+
+    /// Returns the elements of the vector as a slice
+    pub fn as_slice(&self) -> &[T] { &self.data }
+
+    /// Returns the elements of the vector as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] { &mut self.data }
+}
+
+impl<T: VecItem, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self { Self::splat(T::default()) }
+}
+
+impl_vec!(VecN[N], N []);
+
+impl_vecn_unit_consts!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl_vecn_unit_consts!(f32, f64; 0.0, 1.0);
+
+// From traits
+
+impl<T: VecItem, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(arr: [T; N]) -> Self { Self::from_array(arr) }
+}
+
+// Serialization traits
+
+#[cfg(feature = "serialize")]
+mod serialize {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::ser::SerializeTuple;
+    use serde::de::{self, Visitor, SeqAccess};
+
+    use super::{VecN, VecItem};
+
+    impl<T: VecItem + Serialize, const N: usize> Serialize for VecN<T, N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_tuple(N)?;
+            for item in self.data.iter() {
+                s.serialize_element(item)?;
+            }
+            s.end()
+        }
+    }
+
+    struct VecNVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T: VecItem + Deserialize<'de>, const N: usize> Visitor<'de> for VecNVisitor<T, N> {
+        type Value = VecN<T, N>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a vector with {} components", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<VecN<T, N>, A::Error> {
+            let mut data = [T::default(); N];
+            for (i, item) in data.iter_mut().enumerate() {
+                *item = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            Ok(VecN::from_array(data))
+        }
+    }
+
+    impl<'de, T: VecItem + Deserialize<'de>, const N: usize> Deserialize<'de> for VecN<T, N> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_tuple(N, VecNVisitor(PhantomData))
+        }
+    }
+}